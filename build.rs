@@ -0,0 +1,102 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// the numbers of the entries in `dir` named `<prefix><number><suffix>`
+fn numbered(dir: &Path, prefix: &str, suffix: &str) -> Vec<usize> {
+    let mut found: Vec<usize> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("unable to read {}: {}", dir.display(), e))
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+fn require_declared(file: &str, module: &str) {
+    let source = fs::read_to_string(file).unwrap_or_default();
+    if !source.contains(&format!("pub mod {};", module)) {
+        panic!(
+            "{} is not declared in {}, add `pub mod {};`",
+            module, file, module
+        );
+    }
+}
+
+// the (day, name) of every `dayNN-<name>.txt` file in `dir`
+fn example_files(dir: &Path) -> Vec<(usize, String)> {
+    let mut found: Vec<(usize, String)> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("unable to read {}: {}", dir.display(), e))
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let (day, name) = name.strip_prefix("day")?.split_once('-')?;
+            Some((day.parse().ok()?, name.strip_suffix(".txt")?.to_string()))
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+// registers every src/yYYYY/dayNN.rs with the solver registry, so adding a day needs no
+// manual wiring
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=inputs/examples");
+
+    let mut entries = String::new();
+    for year in numbered(Path::new("src"), "y", "") {
+        require_declared("src/lib.rs", &format!("y{}", year));
+
+        let year_dir = format!("src/y{}", year);
+        for day in numbered(Path::new(&year_dir), "day", ".rs") {
+            require_declared(&format!("{}/mod.rs", year_dir), &format!("day{:02}", day));
+            entries.push_str(&format!("        &y{}::day{:02}::Day{},\n", year, day, day));
+        }
+    }
+
+    let registry = format!(
+        "/// Every implemented day, ordered by year and day
+pub fn solvers() -> Vec<&'static dyn DynSolver> {{
+    vec![
+{}    ]
+}}
+",
+        entries
+    );
+
+    // examples are embedded like the inputs, so `--example` works from any directory
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let examples_dir = Path::new(&manifest_dir).join("inputs/examples");
+    let mut examples = String::new();
+    if examples_dir.exists() {
+        for year in numbered(&examples_dir, "", "") {
+            let year_dir = examples_dir.join(year.to_string());
+            for (day, name) in example_files(&year_dir) {
+                let path = year_dir.join(format!("day{:02}-{}.txt", day, name));
+                examples.push_str(&format!(
+                    "        ExampleFile {{ year: {}, day: {}, name: {:?}, text: include_str!({:?}) }},\n",
+                    year, day, name, path
+                ));
+            }
+        }
+    }
+
+    let registry = format!(
+        "{}
+/// Every example under `inputs/examples`, ordered by year, day and name
+pub fn examples() -> Vec<ExampleFile> {{
+    vec![
+{}    ]
+}}
+",
+        registry, examples
+    );
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("solvers.rs");
+    fs::write(out, registry).expect("unable to write solver registry");
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+/// Known correct answers keyed by year and day, read from an `answers.toml` file:
+///
+/// ```toml
+/// [2023.day1]
+/// part1 = 54605
+/// part2 = "55429"
+/// ```
+pub struct Answers {
+    days: HashMap<(usize, usize), [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+
+        Answers::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut days = HashMap::new();
+
+        for (year_key, value) in table.iter() {
+            let year: usize = year_key
+                .parse()
+                .map_err(|_| format!("invalid year key '{}'", year_key))?;
+            let day_tables = value
+                .as_table()
+                .ok_or(format!("expected a table for '{}'", year_key))?;
+
+            for (day_key, value) in day_tables.iter() {
+                let key = format!("{}.{}", year_key, day_key);
+                let day: usize = day_key
+                    .strip_prefix("day")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or(format!("invalid day key '{}'", key))?;
+                let parts = value
+                    .as_table()
+                    .ok_or(format!("expected a table for '{}'", key))?;
+
+                let mut answers = [None, None];
+                for (part, answer) in parts.iter() {
+                    let slot = match part.as_str() {
+                        "part1" => &mut answers[0],
+                        "part2" => &mut answers[1],
+                        _ => return Err(format!("invalid part key '{}.{}'", key, part)),
+                    };
+                    // large answers can be written as strings since toml integers are i64
+                    *slot = Some(match answer {
+                        Value::Integer(n) => n.to_string(),
+                        Value::String(s) => s.clone(),
+                        _ => return Err(format!("invalid answer for '{}.{}'", key, part)),
+                    });
+                }
+
+                days.insert((year, day), answers);
+            }
+        }
+
+        Ok(Answers { days })
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: usize) -> Option<&str> {
+        self.days.get(&(year, day))?.get(part - 1)?.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+
+    const INPUT: &str = "
+[2023.day1]
+part1 = 142
+part2 = \"281\"
+
+[2023.day6]
+part1 = 288
+";
+
+    #[test]
+    fn parse_test() {
+        let answers = Answers::parse(INPUT).unwrap();
+
+        assert_eq!(Some("142"), answers.get(2023, 1, 1));
+        assert_eq!(Some("281"), answers.get(2023, 1, 2));
+        assert_eq!(Some("288"), answers.get(2023, 6, 1));
+        assert_eq!(None, answers.get(2023, 6, 2));
+        assert_eq!(None, answers.get(2023, 2, 1));
+        assert_eq!(None, answers.get(2022, 1, 1));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert!(Answers::parse("[day1]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2023.first]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2023.day1]\npart3 = 1").is_err());
+        assert!(Answers::parse("[2023.day1]\npart1 = 1.5").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::solver::{Part, Solution};
+
+/// Summary statistics over repeated timings of a single phase
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        // nearest rank
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Stats for every phase that ran over repeated solves of a day
+pub struct DayBench {
+    pub runs: usize,
+    pub phases: Vec<(&'static str, Stats)>,
+}
+
+impl DayBench {
+    /// Summarize `solutions` of a day solved for `part`
+    pub fn new(part: Part, solutions: &[Solution]) -> Self {
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+        for solution in solutions {
+            samples[0].push(solution.parse_time);
+            samples[1].push(solution.part1_time);
+            samples[2].push(solution.part2_time);
+        }
+
+        let phases = [
+            ("parse", true),
+            ("part1", part.runs_part1()),
+            ("part2", part.runs_part2()),
+        ];
+        DayBench {
+            runs: solutions.len(),
+            phases: phases
+                .into_iter()
+                .zip(samples.iter())
+                .filter(|((_, ran), _)| *ran)
+                .map(|((name, _), times)| (name, Stats::new(times)))
+                .collect(),
+        }
+    }
+
+    /// Mean time of a whole solve
+    pub fn mean(&self) -> Duration {
+        self.phases.iter().map(|(_, s)| s.mean).sum()
+    }
+
+    /// A table of the stats of every phase, with each phase's share of the mean
+    pub fn render(&self) -> String {
+        let total = self.mean().as_secs_f64().max(f64::MIN_POSITIVE);
+        let mut table = format!(
+            "{:<6}{:>12}{:>12}{:>12}{:>12}{:>12}{:>8}\n",
+            "Phase", "min", "median", "mean", "p95", "stddev", "share"
+        );
+
+        for (name, s) in self.phases.iter() {
+            let _ = writeln!(
+                table,
+                "{:<6}{:>12}{:>12}{:>12}{:>12}{:>12}{:>7.1}%",
+                name,
+                format_us(s.min),
+                format_us(s.median),
+                format_us(s.mean),
+                format_us(s.p95),
+                format_us(s.stddev),
+                100.0 * s.mean.as_secs_f64() / total
+            );
+        }
+
+        table
+    }
+}
+
+fn format_us(d: Duration) -> String {
+    format!("{:.1}us", d.as_secs_f64() * 1e6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DayBench, Stats};
+    use crate::solver::{Part, Solution};
+    use std::time::Duration;
+
+    #[test]
+    fn stats_test() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(&samples);
+
+        assert_eq!(Duration::from_micros(1), stats.min);
+        assert_eq!(Duration::from_micros(3), stats.median);
+        assert_eq!(Duration::from_micros(3), stats.mean);
+        assert_eq!(Duration::from_micros(5), stats.p95);
+        assert_eq!(1414, stats.stddev.as_nanos());
+    }
+
+    #[test]
+    fn median_even_test() {
+        let samples: Vec<Duration> = [4, 1, 2, 3].map(Duration::from_micros).to_vec();
+
+        assert_eq!(Duration::from_nanos(2500), Stats::new(&samples).median);
+    }
+
+    #[test]
+    fn day_bench_test() {
+        let solution = |parse, part1| Solution {
+            part1: Some("1".to_string()),
+            part2: None,
+            parse_time: Duration::from_micros(parse),
+            part1_time: Duration::from_micros(part1),
+            part2_time: Duration::ZERO,
+        };
+        let bench = DayBench::new(Part::One, &[solution(10, 20), solution(30, 60)]);
+
+        assert_eq!(2, bench.runs);
+        assert_eq!(Duration::from_micros(60), bench.mean());
+        assert_eq!(
+            "Phase          min      median        mean         p95      stddev   share\n\
+             parse       10.0us      20.0us      20.0us      30.0us      10.0us   33.3%\n\
+             part1       20.0us      40.0us      40.0us      60.0us      20.0us   66.7%\n",
+            bench.render()
+        );
+    }
+}
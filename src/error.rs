@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why a day's input failed to parse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: usize,
+    /// 1-based line number within the input
+    pub line: usize,
+    /// 1-based column of the offending text within the line
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error on `part` of the line at `line_idx`. `part` has to be a slice of `line`,
+    /// its offset is used as the column.
+    pub fn at(line_idx: usize, line: &str, part: &str, message: &str) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+        ParseError {
+            day: 0,
+            line: line_idx + 1,
+            column: offset.min(line.len()) + 1,
+            text: part.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error just past the end of the line, for things that are missing entirely
+    pub fn at_end(line_idx: usize, line: &str, message: &str) -> Self {
+        ParseError::at(line_idx, line, &line[line.len()..], message)
+    }
+
+    /// Render as `source:line:column: message`, e.g. `inputs/2023/day07:412:6: expected bid`
+    pub fn render(&self, source: &str) -> String {
+        format!("{}:{}", source, self)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `part`, a slice of `line`, as a number
+pub fn parse_number<T: FromStr>(line_idx: usize, line: &str, part: &str) -> Result<T, ParseError> {
+    part.parse()
+        .map_err(|_| ParseError::at(line_idx, line, part, "expected a number, found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_number, ParseError};
+
+    #[test]
+    fn column_test() {
+        let line = "32T3K 765";
+        let err = ParseError::at(411, line, &line[6..], "invalid bid");
+
+        assert_eq!(412, err.line);
+        assert_eq!(7, err.column);
+        assert_eq!(
+            "inputs/day7:412:7: invalid bid '765'",
+            err.render("inputs/day7")
+        );
+    }
+
+    #[test]
+    fn at_end_test() {
+        let err = ParseError::at_end(0, "32T3K", "expected bid after hand");
+
+        assert_eq!(6, err.column);
+        assert_eq!("1:6: expected bid after hand", err.to_string());
+    }
+
+    #[test]
+    fn parse_number_test() {
+        let line = "Time: 7 x5";
+
+        assert_eq!(Ok(7), parse_number::<u32>(0, line, &line[6..7]));
+        assert_eq!(
+            9,
+            parse_number::<u32>(0, line, &line[8..]).unwrap_err().column
+        );
+    }
+}
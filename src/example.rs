@@ -0,0 +1,118 @@
+use crate::input::normalize;
+use crate::solver::Part;
+
+/// An example from a puzzle description with its expected answers. Examples are kept in
+/// `inputs/examples/<year>/dayNN-<name>.txt` as `partN: answer` lines, a blank line and
+/// then the example input:
+///
+/// ```text
+/// part1: 142
+///
+/// 1abc2
+/// pqr3stu8vwx
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = normalize(text);
+        let (header, input) = text
+            .split_once("\n\n")
+            .ok_or("expected answers, a blank line and then the example input")?;
+
+        let mut example = Example {
+            part1: None,
+            part2: None,
+            input: input.to_string(),
+        };
+        for (i, line) in header.lines().enumerate() {
+            let (part, answer) = line
+                .split_once(':')
+                .map(|(part, answer)| (part.trim(), answer.trim()))
+                .filter(|(_, answer)| !answer.is_empty())
+                .ok_or(format!("line {}: expected `part1: <answer>`", i + 1))?;
+            let slot = match part {
+                "part1" => &mut example.part1,
+                "part2" => &mut example.part2,
+                _ => return Err(format!("line {}: invalid part '{}'", i + 1, part)),
+            };
+            *slot = Some(answer.to_string());
+        }
+
+        Ok(example)
+    }
+
+    /// The parts of `part` this example has an answer for, if any
+    pub fn parts(&self, part: Part) -> Option<Part> {
+        let part1 = part.runs_part1() && self.part1.is_some();
+        let part2 = part.runs_part2() && self.part2.is_some();
+
+        match (part1, part2) {
+            (true, true) => Some(Part::Both),
+            (true, false) => Some(Part::One),
+            (false, true) => Some(Part::Two),
+            (false, false) => None,
+        }
+    }
+}
+
+/// An example file embedded at build time, see `build.rs`
+#[derive(Debug, Clone, Copy)]
+pub struct ExampleFile {
+    pub year: usize,
+    pub day: usize,
+    /// What follows the day in the file name, e.g. `2` for `day08-2.txt`
+    pub name: &'static str,
+    pub text: &'static str,
+}
+
+impl ExampleFile {
+    /// Where the file lives relative to the inputs directory
+    pub fn path(&self) -> String {
+        format!(
+            "examples/{}/day{:02}-{}.txt",
+            self.year, self.day, self.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Example;
+    use crate::solver::Part;
+
+    const INPUT: &str = "part1: 2
+part2: 6
+
+RL
+
+AAA = (AAA, AAA)
+";
+
+    #[test]
+    fn parse_test() {
+        let example = Example::parse(INPUT).unwrap();
+
+        assert_eq!(Some("2"), example.part1.as_deref());
+        assert_eq!(Some("6"), example.part2.as_deref());
+        assert_eq!("RL\n\nAAA = (AAA, AAA)", example.input);
+
+        assert!(Example::parse("part1: 2\nRL").is_err());
+        assert!(Example::parse("part3: 2\n\nRL").is_err());
+        assert!(Example::parse("part1:\n\nRL").is_err());
+    }
+
+    #[test]
+    fn parts_test() {
+        let example = Example::parse("part2: 6\n\nLR").unwrap();
+
+        assert_eq!(Some(Part::Two), example.parts(Part::Both));
+        assert_eq!(Some(Part::Two), example.parts(Part::Two));
+        assert_eq!(None, example.parts(Part::One));
+    }
+}
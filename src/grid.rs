@@ -0,0 +1,294 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+/// A cell position as (row, column), counted from the top left
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbors: north, east, south and west
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all 8 neighbors, clockwise from north
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A dense rectangular grid, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse one cell per character with `cell`, one row per line. Characters `cell`
+    /// rejects are reported as unknown tiles, and every row must be as wide as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        if width == 0 {
+            return Err(ParseError::at_end(0, "", "expected a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (idx, line) in lines.iter().enumerate() {
+            for (i, c) in line.char_indices() {
+                let tile = &line[i..i + c.len_utf8()];
+                let value =
+                    cell(c).ok_or_else(|| ParseError::at(idx, line, tile, "unknown tile"))?;
+                cells.push(value);
+            }
+            if cells.len() != width * (idx + 1) {
+                let msg = format!("expected {} tiles per row", width);
+                return Err(ParseError::at_end(idx, line, &msg));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Move from `pos` by a (row, column) offset, if that stays on the grid
+    pub fn step(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbors of `pos` that are on the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are on the grid
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    // by index rather than `chunks`, which panics for a zero width
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+/// Cells are written one after another with a newline between rows, so a `Grid<char>`
+/// prints back as the text it was parsed from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "#..
+.#.
+..#
+#..";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| matches!(c, '.' | '#').then_some(c)).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = grid();
+
+        assert_eq!((3, 4), (grid.width(), grid.height()));
+        assert_eq!('#', grid[(3, 0)]);
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(INPUT, grid.to_string());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let tile = |c| matches!(c, '.' | '#').then_some(c);
+        let err = Grid::parse("#..\n.x.", tile).unwrap_err();
+        assert_eq!("2:2: unknown tile 'x'", err.to_string());
+
+        let err = Grid::parse("#..\n..", Some).unwrap_err();
+        assert_eq!("2:3: expected 3 tiles per row", err.to_string());
+
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let grid = grid();
+
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbors8((3, 2)).count());
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+        assert_eq!(None, grid.step((0, 2), (0, 1)));
+        assert_eq!(Some((1, 1)), grid.step((0, 2), (1, -1)));
+    }
+
+    #[test]
+    fn rows_and_columns_test() {
+        let grid = grid();
+
+        assert_eq!(&['.', '#', '.'], grid.row(1));
+        assert_eq!(4, grid.rows().count());
+        assert_eq!("#..#", grid.column(0).collect::<String>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(
+            vec![(0, 0), (1, 1), (2, 2), (3, 0)],
+            grid.iter()
+                .filter(|(_, &c)| c == '#')
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn transpose_test() {
+        let transposed = grid().transpose();
+
+        assert_eq!("#..#\n.#..\n..#.", transposed.to_string());
+        assert_eq!(grid(), transposed.transpose());
+
+        let mut marked = transposed.map(|&c| c == '#');
+        marked[(0, 3)] = false;
+        assert_eq!(&[true, false, false, false], marked.row(0));
+    }
+
+    #[test]
+    fn empty_test() {
+        let narrow = Grid::new(0, 2, '.');
+        assert_eq!(2, narrow.rows().count());
+        assert_eq!("\n", narrow.to_string());
+
+        let flat = Grid::new(3, 0, '.').transpose();
+        assert_eq!((0, 3), (flat.width(), flat.height()));
+        assert_eq!("\n\n", flat.to_string());
+        assert_eq!(0, flat.columns().count());
+        assert_eq!("", Grid::new(0, 0, '.').to_string());
+    }
+}
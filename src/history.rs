@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// How the site judged a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The part was already solved, or is not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate limited",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown response",
+        }
+    }
+
+    fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch
+    pub time: u64,
+}
+
+/// Every answer submitted so far, kept in a toml file as a list of `[[attempt]]` tables
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load the history, starting empty if the file does not exist yet
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+        toml::from_str(&text)
+            .map_err(|e: toml::de::Error| format!("{}: {}", path.display(), e.message()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| format!("unable to write {}: {}", path.display(), e))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Refuse answers that can't be right given earlier attempts: a part that is already
+    /// solved, a value that was rejected before, or one outside the too high/too low bounds
+    pub fn check(&self, year: usize, day: usize, part: usize, answer: &str) -> Result<(), String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part);
+        let value = answer.parse::<i128>().ok();
+
+        for attempt in attempts {
+            let known = attempt.answer.parse::<i128>().ok();
+            let out_of_bounds = match (attempt.verdict, value, known) {
+                (Verdict::TooHigh, Some(value), Some(high)) => value >= high,
+                (Verdict::TooLow, Some(value), Some(low)) => value <= low,
+                _ => false,
+            };
+
+            if attempt.verdict == Verdict::Correct {
+                return Err(format!("already solved with {}", attempt.answer));
+            }
+            if attempt.verdict.is_rejection() && attempt.answer == answer {
+                return Err(format!(
+                    "{} was already rejected as {}",
+                    answer,
+                    attempt.verdict.as_str()
+                ));
+            }
+            if out_of_bounds {
+                return Err(format!(
+                    "{} can't be right, {} was {}",
+                    answer,
+                    attempt.answer,
+                    attempt.verdict.as_str()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Attempt, History, Verdict};
+
+    fn attempt(part: usize, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2023,
+            day: 5,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn check_test() {
+        let history = History {
+            attempts: vec![
+                attempt(1, "1414626613", Verdict::TooHigh),
+                attempt(1, "1000", Verdict::TooLow),
+                attempt(1, "5000", Verdict::RateLimited),
+                attempt(2, "104070862", Verdict::Correct),
+            ],
+        };
+
+        assert!(history.check(2023, 5, 1, "324724204").is_ok());
+        assert!(history.check(2023, 5, 1, "5000").is_ok());
+        assert!(history.check(2023, 5, 1, "1414626613").is_err());
+        assert!(history.check(2023, 5, 1, "1500000000").is_err());
+        assert!(history.check(2023, 5, 1, "999").is_err());
+        assert!(history.check(2023, 5, 2, "104070862").is_err());
+        assert!(history.check(2023, 6, 1, "1414626613").is_ok());
+        assert!(history.check(2022, 5, 1, "1414626613").is_ok());
+    }
+
+    #[test]
+    fn toml_test() {
+        let mut history = History::default();
+        history.record(attempt(1, "324724204", Verdict::TooHigh));
+
+        let text = toml::to_string(&history).unwrap();
+        assert!(text.contains("[[attempt]]"));
+        assert!(text.contains("verdict = \"too-high\""));
+
+        let parsed: History = toml::from_str(&text).unwrap();
+        assert_eq!(history.attempts, parsed.attempts);
+    }
+}
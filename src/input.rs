@@ -0,0 +1,71 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Clean up raw input text before any solver sees it: drop a byte order mark, turn
+/// CRLF line endings into LF and remove trailing blank lines along with the final newline
+pub fn normalize(raw: &str) -> String {
+    let text = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut lines: Vec<&str> = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+/// Where the input for a day lives relative to the inputs directory, e.g. `2023/day05`
+pub fn day_path(year: usize, day: usize) -> String {
+    format!("{}/day{:02}", year, day)
+}
+
+/// Load the input for `day` of `year`. An explicit `input` path wins over
+/// `inputs_dir/<year>/dayNN`, with `-` meaning stdin; the embedded input is the fallback.
+/// Returns where the input came from, for error messages, along with the normalized text.
+pub fn load(
+    year: usize,
+    day: usize,
+    embedded: &str,
+    input: Option<&Path>,
+    inputs_dir: Option<&Path>,
+) -> Result<(String, String), String> {
+    let (source, raw) = match (input, inputs_dir) {
+        (Some(path), _) if path.as_os_str() == "-" => {
+            let raw = io::read_to_string(io::stdin())
+                .map_err(|e| format!("unable to read stdin: {}", e))?;
+            ("<stdin>".to_string(), raw)
+        }
+        (None, None) => (
+            format!("inputs/{}", day_path(year, day)),
+            embedded.to_string(),
+        ),
+        (Some(path), _) => read(path)?,
+        (None, Some(dir)) => read(&dir.join(day_path(year, day)))?,
+    };
+
+    Ok((source, normalize(&raw)))
+}
+
+fn read(path: &Path) -> Result<(String, String), String> {
+    fs::read_to_string(path)
+        .map(|raw| (path.display().to_string(), raw))
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!("a\nb", normalize("a\nb"));
+        assert_eq!("a\nb", normalize("\u{feff}a\r\nb\r\n"));
+        assert_eq!("a\n\nb", normalize("a\r\n\r\nb\n\n  \n"));
+        assert_eq!("\n  a", normalize("\n  a\n"));
+        assert_eq!("", normalize("\r\n"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Why an isolated run did not produce a value
+#[derive(Debug, PartialEq, Eq)]
+pub enum Failure {
+    Panic(String),
+    /// A panic about arithmetic overflow, e.g. `attempt to multiply with overflow` when
+    /// built with overflow checks, or checked arithmetic giving up through [`overflowed`]
+    Overflow(String),
+    Timeout(Duration),
+}
+
+/// Panic payload of [`overflowed`]
+#[derive(Debug)]
+pub struct Overflowed(pub String);
+
+/// Give up on a solve whose checked arithmetic overflowed. Isolated runs report it as a
+/// [`Failure::Overflow`], just like the panics of builds with overflow checks.
+pub fn overflowed(what: &str) -> ! {
+    panic::panic_any(Overflowed(format!("{} overflowed", what)))
+}
+
+thread_local! {
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keep panics inside isolated runs off stderr, they are reported with the day instead.
+/// Panics anywhere else still go through the default hook.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        if ISOLATED.with(|isolated| isolated.get()) {
+            let location = info.location().map(|l| l.to_string());
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(Overflowed(msg)) = payload.downcast_ref() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+
+    match PANIC_LOCATION.with(|cell| cell.borrow_mut().take()) {
+        Some(location) => format!("{} at {}", message, location),
+        None => message,
+    }
+}
+
+fn catch<T, F: FnOnce() -> T>(f: F) -> Result<T, Failure> {
+    ISOLATED.with(|isolated| isolated.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.with(|isolated| isolated.set(false));
+
+    result.map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+        // only the compiler's own arithmetic panics, not any message that mentions overflow
+        let arithmetic = message.starts_with("attempt to ") && message.contains(" with overflow");
+        if arithmetic || payload.is::<Overflowed>() {
+            Failure::Overflow(message)
+        } else {
+            Failure::Panic(message)
+        }
+    })
+}
+
+/// Run `f`, turning a panic into a [`Failure`]. With a timeout the work runs on its own
+/// thread, which is left behind (not killed) if it overruns.
+pub fn run_isolated<T, F>(f: F, timeout: Option<Duration>) -> Result<T, Failure>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return catch(f);
+    };
+
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(catch(f));
+    });
+
+    rx.recv_timeout(timeout)
+        .unwrap_or(Err(Failure::Timeout(timeout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overflowed, run_isolated, Failure};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn ok_test() {
+        assert_eq!(Ok(4), run_isolated(|| 2 + 2, None));
+        assert_eq!(Ok(4), run_isolated(|| 2 + 2, Some(Duration::from_secs(5))));
+    }
+
+    #[test]
+    fn panic_test() {
+        let result: Result<(), Failure> = run_isolated(|| panic!("Invalid direction"), None);
+
+        match result {
+            Err(Failure::Panic(msg)) => assert!(msg.starts_with("Invalid direction")),
+            _ => panic!("expected a panic failure"),
+        }
+    }
+
+    #[test]
+    fn overflow_test() {
+        // division overflow panics whether or not overflow checks are on
+        let result = run_isolated(|| std::hint::black_box(i8::MIN) / -1, None);
+
+        match result {
+            Err(Failure::Overflow(msg)) => assert!(msg.starts_with("attempt to divide")),
+            _ => panic!("expected an overflow failure"),
+        }
+
+        let result: Result<(), Failure> = run_isolated(|| overflowed("lcm of the steps"), None);
+        match result {
+            Err(Failure::Overflow(msg)) => assert!(msg.starts_with("lcm of the steps overflowed")),
+            _ => panic!("expected an overflow failure"),
+        }
+
+        let result: Result<(), Failure> = run_isolated(|| panic!("buffer overflows"), None);
+        assert!(matches!(result, Err(Failure::Panic(_))));
+    }
+
+    #[test]
+    fn timeout_test() {
+        let timeout = Duration::from_millis(10);
+        let result = run_isolated(|| thread::sleep(Duration::from_secs(5)), Some(timeout));
+
+        assert_eq!(Err(Failure::Timeout(timeout)), result);
+    }
+}
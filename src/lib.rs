@@ -0,0 +1,31 @@
+//! Advent of Code solvers, one module per year. Each `yYYYY::dayNN` module exposes its
+//! parser, both parts and the helpers they are built from; [`solvers`] lists every day
+//! for the CLI. Days are picked up from `src/yYYYY/dayNN.rs` at build time, see `build.rs`.
+
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod example;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod isolate;
+pub mod math;
+pub mod memory;
+pub mod parallel;
+pub mod parse;
+pub mod range;
+pub mod readme;
+pub mod remote;
+pub mod report;
+pub mod scaffold;
+pub mod select;
+pub mod solver;
+
+pub mod y2023;
+
+use example::ExampleFile;
+use solver::DynSolver;
+
+// generated by build.rs from the dayNN.rs files under src and the example inputs
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
@@ -1,93 +1,760 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use structopt::{self, StructOpt};
 
-pub enum ReturnSize {
-    U32((u32, u32)),
-    I64((i64, i64)),
-    USIZE((usize, usize)),
-    U128((u128, u128)),
-    Str((String, String)),
-}
-
-mod day1;
-mod day10;
-mod day11;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+use adventofcode23::answers::Answers;
+use adventofcode23::bench::DayBench;
+use adventofcode23::error::ParseError;
+use adventofcode23::example::{Example, ExampleFile};
+use adventofcode23::history::Verdict;
+use adventofcode23::input;
+use adventofcode23::isolate::{self, Failure};
+use adventofcode23::memory;
+use adventofcode23::parallel;
+use adventofcode23::readme;
+use adventofcode23::remote::{self, Config, Fetched};
+use adventofcode23::report::{self, micros, sha256_hex, DayRecord, Format, Status, Summary};
+use adventofcode23::scaffold;
+use adventofcode23::select::{self, DaySpec};
+use adventofcode23::solver::{DynSolver, Part};
 
 #[derive(Debug, StructOpt)]
-#[structopt(name = "AoC 2023", about = "Solver for Advent of Code 2023 Challenges")]
+#[structopt(name = "AoC", about = "Solver for Advent of Code Challenges")]
 struct Opt {
+    /// Event year; days are picked from the latest year unless given
     #[structopt(short, long)]
-    day: Option<usize>,
+    year: Option<usize>,
+
+    /// Days to run, e.g. 1-5,8,10
+    #[structopt(short, long, alias = "day")]
+    days: Option<DaySpec>,
+
+    /// Days to leave out, e.g. 10
+    #[structopt(long)]
+    skip: Option<DaySpec>,
+
+    /// Run only the most recent day
+    #[structopt(long, conflicts_with = "days")]
+    latest: bool,
+
+    /// Part to run: 1, 2 or both
+    #[structopt(short, long, default_value = "both")]
+    part: Part,
+
+    /// Input file for the selected day, `-` for stdin
+    #[structopt(short, long, parse(from_os_str))]
+    input: Option<PathBuf>,
+
+    /// Directory containing `<year>/dayNN` input files
+    #[structopt(long, parse(from_os_str))]
+    inputs_dir: Option<PathBuf>,
+
+    /// Solve the example inputs from the puzzle descriptions and check their answers
+    #[structopt(long, conflicts_with_all = &["input", "inputs-dir"])]
+    example: bool,
+
+    /// List the available days and exit
+    #[structopt(long)]
+    list: bool,
+
+    /// Time each phase over this many runs and report statistics
+    #[structopt(long)]
+    bench: Option<usize>,
+
+    /// Number of days to solve in parallel
+    #[structopt(short, long, default_value = "1")]
+    jobs: usize,
+
+    /// Give up on a day after this many seconds
+    #[structopt(long, parse(try_from_str = parse_seconds))]
+    timeout: Option<Duration>,
+
+    /// Output format: text, json or csv
+    #[structopt(short, long, default_value = "text")]
+    format: Format,
+
+    /// Answers file keyed by year, day and part
+    #[structopt(long, parse(from_os_str), default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Config file with the session cookie and site URL
+    #[structopt(long, parse(from_os_str), default_value = "aoc.toml")]
+    config: PathBuf,
+
+    #[structopt(subcommand)]
+    cmd: Option<Command>,
 }
 
-fn main() {
-    let mut total_time = Duration::new(0, 0);
-    let day_funcs = Vec::from([
-        day1::solve_day,
-        day2::solve_day,
-        day3::solve_day,
-        day4::solve_day,
-        day5::solve_day,
-        day6::solve_day,
-        day7::solve_day,
-        day8::solve_day,
-        day9::solve_day,
-        day10::solve_day,
-        day11::solve_day,
-    ]);
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Check solver output against the known answers
+    Verify,
+    /// Render the README with a table of answers and timings
+    Readme {
+        /// Only check that the README is up to date, ignoring timings
+        #[structopt(long)]
+        check: bool,
 
-    let opt = Opt::from_args();
+        #[structopt(long, parse(from_os_str), default_value = "README.md")]
+        path: PathBuf,
+    },
+    /// Generate and register a module for a new day
+    NewDay {
+        day: usize,
+
+        /// Puzzle title, shown by --list
+        #[structopt(long)]
+        title: Option<String>,
+    },
+    /// Download missing puzzle inputs into the inputs directory
+    Fetch {
+        /// Site to download from instead of the configured one
+        #[structopt(long)]
+        base_url: Option<String>,
+    },
+    /// Solve a part and submit the answer, keeping a history of attempts
+    Submit {
+        #[structopt(short, long)]
+        day: usize,
+
+        /// Part to submit: 1 or 2
+        #[structopt(short, long)]
+        part: usize,
 
-    let (days_to_run, mut day) = match opt.day {
-        Some(day) => (vec![day_funcs[day - 1]], day),
-        None => (day_funcs, 1),
+        /// Site to submit to instead of the configured one
+        #[structopt(long)]
+        base_url: Option<String>,
+
+        /// File recording every submitted answer
+        #[structopt(long, parse(from_os_str), default_value = "submissions.toml")]
+        history: PathBuf,
+    },
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|d| !d.is_zero())
+        .ok_or(format!("invalid number of seconds '{}'", s))
+}
+
+fn load_input(solver: &dyn DynSolver, opt: &Opt) -> Result<(String, String), String> {
+    input::load(
+        solver.year(),
+        solver.day(),
+        solver.input(),
+        opt.input.as_deref(),
+        opt.inputs_dir.as_deref(),
+    )
+}
+
+fn solve_day(solver: &'static dyn DynSolver, opt: &Opt, answers: Option<&Answers>) -> DayRecord {
+    let (year, day) = (solver.year(), solver.day());
+    let mut record = DayRecord::new(year, day, solver.title());
+
+    match load_input(solver, opt) {
+        Ok((source, input)) => {
+            let expected = answers.map_or([None, None], |answers| {
+                [answers.get(year, day, 1), answers.get(year, day, 2)]
+            });
+            solve_input(
+                solver,
+                &mut record,
+                (&source, 0),
+                input,
+                opt.part,
+                expected,
+                opt.timeout,
+            );
+        }
+        Err(e) => {
+            record.status = Status::Error;
+            record.error = Some(e);
+        }
+    }
+
+    record
+}
+
+fn solve_example(solver: &'static dyn DynSolver, file: &ExampleFile, opt: &Opt) -> DayRecord {
+    let mut record = DayRecord::new(solver.year(), solver.day(), solver.title());
+    record.example = Some(file.name);
+    let source = format!("inputs/{}", file.path());
+
+    match Example::parse(file.text) {
+        Ok(example) => {
+            // parse errors should point into the file, past the answers and the blank line
+            let header_lines = file
+                .text
+                .lines()
+                .take_while(|line| !line.trim().is_empty())
+                .count()
+                + 1;
+            let part = example.parts(opt.part).unwrap_or(opt.part);
+            let expected = [example.part1.as_deref(), example.part2.as_deref()];
+            solve_input(
+                solver,
+                &mut record,
+                (&source, header_lines),
+                example.input.clone(),
+                part,
+                expected,
+                opt.timeout,
+            );
+        }
+        Err(e) => {
+            record.status = Status::Error;
+            record.error = Some(format!("{}: {}", source, e));
+        }
+    }
+
+    record
+}
+
+// fills in `record` with the solution for `input`, which starts after `skipped_lines` of
+// `source`; an answer that differs from an `expected` one is a mismatch
+fn solve_input(
+    solver: &'static dyn DynSolver,
+    record: &mut DayRecord,
+    (source, skipped_lines): (&str, usize),
+    input: String,
+    part: Part,
+    expected: [Option<&str>; 2],
+    timeout: Option<Duration>,
+) {
+    record.input_sha256 = Some(sha256_hex(&input));
+
+    let solve = move || memory::measure(|| solver.solve(&input, part));
+    let solution = match isolate::run_isolated(solve, timeout) {
+        Ok((Ok(solution), allocs)) => {
+            record.allocs = allocs;
+            solution
+        }
+        Ok((Err(e), _)) => {
+            let e = ParseError {
+                line: e.line + skipped_lines,
+                ..e
+            };
+            record.status = Status::Error;
+            record.error = Some(e.render(source));
+            return;
+        }
+        Err(failure) => {
+            let (status, error) = describe_failure(failure);
+            record.status = status;
+            record.error = Some(error);
+            return;
+        }
     };
 
-    for solve_fn in days_to_run.iter() {
-        let start_time = Instant::now();
-        let answer = solve_fn();
-        let elapsed: Duration = Instant::now() - start_time;
+    record.parse_us = micros(solution.parse_time);
+    record.part1_us = micros(solution.part1_time);
+    record.part2_us = micros(solution.part2_time);
+    record.total_us = micros(solution.total_time());
+
+    let mismatches: Vec<String> = [&solution.part1, &solution.part2]
+        .into_iter()
+        .zip(expected)
+        .enumerate()
+        .filter_map(|(i, answers)| match answers {
+            (Some(actual), Some(expected)) if expected != actual => Some(format!(
+                "part {} expected {}, got {}",
+                i + 1,
+                expected,
+                actual
+            )),
+            _ => None,
+        })
+        .collect();
+
+    if !mismatches.is_empty() {
+        record.status = Status::Mismatch;
+        record.error = Some(mismatches.join("; "));
+    }
+
+    record.part1 = solution.part1;
+    record.part2 = solution.part2;
+}
+
+fn describe_failure(failure: Failure) -> (Status, String) {
+    match failure {
+        Failure::Panic(msg) => (Status::Panic, format!("panicked: {}", msg)),
+        Failure::Overflow(msg) => (Status::Overflow, format!("overflowed: {}", msg)),
+        Failure::Timeout(timeout) => (Status::Timeout, format!("timed out after {:?}", timeout)),
+    }
+}
+
+// the examples of the given days that have an answer for a part being run
+fn select_examples(
+    days_to_run: &[&'static dyn DynSolver],
+    part: Part,
+) -> Vec<(&'static dyn DynSolver, ExampleFile)> {
+    let examples = adventofcode23::examples();
 
-        match answer {
-            ReturnSize::U32(ans) => {
-                println!("Day {:02} Part 1: {}", day, ans.0);
-                println!("Day {:02} Part 2: {}", day, ans.1);
+    days_to_run
+        .iter()
+        .flat_map(|solver| {
+            examples
+                .iter()
+                .filter(|file| (file.year, file.day) == (solver.year(), solver.day()))
+                .filter(|file| Example::parse(file.text).map_or(true, |e| e.parts(part).is_some()))
+                .map(|file| (*solver, *file))
+        })
+        .collect()
+}
+
+fn run(days_to_run: &[&'static dyn DynSolver], opt: &Opt) -> usize {
+    // answers are optional outside of verify; without them nothing is a mismatch.
+    // they belong to our own puzzle inputs, so --input and --inputs-dir are never checked
+    let default_inputs = opt.input.is_none() && opt.inputs_dir.is_none();
+    let answers = if opt.answers.exists() && default_inputs && !opt.example {
+        match Answers::load(&opt.answers) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return days_to_run.len();
             }
-            ReturnSize::I64(ans) => {
-                println!("Day {:02} Part 1: {}", day, ans.0);
-                println!("Day {:02} Part 2: {}", day, ans.1);
+        }
+    } else {
+        None
+    };
+
+    let print = |record: &DayRecord| {
+        if opt.format == Format::Text {
+            report::print_text_record(record);
+        }
+    };
+
+    let start_time = Instant::now();
+    let records = if opt.example {
+        let examples = select_examples(days_to_run, opt.part);
+        if examples.is_empty() {
+            eprintln!("Error: no examples for the selected days");
+            return 1;
+        }
+        parallel::run_ordered(
+            &examples,
+            opt.jobs,
+            |(solver, file)| solve_example(*solver, file, opt),
+            print,
+        )
+    } else {
+        let answers = answers.as_ref();
+        parallel::run_ordered(
+            days_to_run,
+            opt.jobs,
+            |solver| solve_day(*solver, opt, answers),
+            print,
+        )
+    };
+    let wall_time = start_time.elapsed();
+
+    let summary = Summary::new(&records, wall_time);
+    match opt.format {
+        Format::Text => report::print_text_summary(&summary),
+        Format::Json => report::print_json(&records, &summary),
+        Format::Csv => report::print_csv(&records, &summary),
+    }
+
+    records.iter().filter(|r| r.status != Status::Ok).count()
+}
+
+fn verify(days_to_run: &[&'static dyn DynSolver], opt: &Opt, answers: &Path) -> bool {
+    let answers = match Answers::load(answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return false;
+        }
+    };
+
+    let mut checked = 0;
+    let mut failed = 0;
+
+    println!(
+        "Year  Day  Part  {:<16}  {:<16}  Status",
+        "Expected", "Actual"
+    );
+    for solver in days_to_run.iter() {
+        let record = solve_day(*solver, opt, Some(&answers));
+        let (year, day) = (record.year, record.day);
+
+        if matches!(
+            record.status,
+            Status::Error | Status::Panic | Status::Overflow | Status::Timeout
+        ) {
+            println!(
+                "{}  {:02}   -     FAILED: {}",
+                year,
+                day,
+                record.error.unwrap_or_default()
+            );
+            failed += 1;
+            continue;
+        }
+
+        for (part, actual) in [(1, record.part1), (2, record.part2)] {
+            let Some(actual) = actual else {
+                continue;
+            };
+
+            let expected = answers.get(year, day, part);
+            let status = match expected {
+                Some(expected) if expected == actual => "PASS",
+                Some(_) => "FAIL",
+                None => "UNKNOWN",
+            };
+            if status == "FAIL" {
+                failed += 1;
             }
-            ReturnSize::U128(ans) => {
-                println!("Day {:02} Part 1: {}", day, ans.0);
-                println!("Day {:02} Part 2: {}", day, ans.1);
+            checked += 1;
+
+            println!(
+                "{}  {:02}   {:<4}  {:<16}  {:<16}  {}",
+                year,
+                day,
+                part,
+                expected.unwrap_or("-"),
+                actual,
+                status
+            );
+        }
+    }
+
+    println!("\n{} checked, {} failed", checked, failed);
+
+    failed == 0
+}
+
+fn bench(days_to_run: &[&'static dyn DynSolver], opt: &Opt, runs: usize) -> bool {
+    let mut total_mean = Duration::new(0, 0);
+    let mut failed = 0;
+
+    for &solver in days_to_run.iter() {
+        let day = solver.day();
+        let (source, input) = match load_input(solver, opt) {
+            Ok((source, input)) => (source, Arc::<str>::from(input)),
+            Err(e) => {
+                println!("Day {:02} Error: {}\n", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        // every run is isolated like a normal solve, so a failing day doesn't end the bench
+        let mut solutions = Vec::with_capacity(runs);
+        let error = loop {
+            if solutions.len() == runs {
+                break None;
+            }
+            let (input, part) = (Arc::clone(&input), opt.part);
+            match isolate::run_isolated(move || solver.solve(&input, part), opt.timeout) {
+                Ok(Ok(solution)) => solutions.push(solution),
+                Ok(Err(e)) => break Some(e.render(&source)),
+                Err(failure) => break Some(describe_failure(failure).1),
+            }
+        };
+        if let Some(e) = error {
+            println!("Day {:02} Error: {}\n", day, e);
+            failed += 1;
+            continue;
+        }
+
+        let day_bench = DayBench::new(opt.part, &solutions);
+
+        println!("Day {:02} Bench: {} runs", day, runs);
+        println!("{}", day_bench.render());
+
+        total_mean += day_bench.mean();
+    }
+
+    println!("Total Mean Solve Time: {:.5}s", total_mean.as_secs_f64());
+
+    failed == 0
+}
+
+fn write_readme(solvers: &[&'static dyn DynSolver], opt: &Opt, path: &Path, check: bool) -> bool {
+    let start_time = Instant::now();
+    let records = parallel::run_ordered(
+        solvers,
+        opt.jobs,
+        |solver| solve_day(*solver, opt, None),
+        |_| {},
+    );
+    let summary = Summary::new(&records, start_time.elapsed());
+
+    if let Some(r) = records.iter().find(|r| r.status != Status::Ok) {
+        eprintln!(
+            "Error: day {:02} failed: {}",
+            r.day,
+            r.error.as_deref().unwrap_or("")
+        );
+        return false;
+    }
+
+    let rendered = readme::render(&records, &summary);
+
+    if check {
+        let existing = fs::read_to_string(path).unwrap_or_default();
+        if !readme::is_current(&existing, &rendered) {
+            eprintln!(
+                "{} is out of date, run `cargo run --release -- readme`",
+                path.display()
+            );
+            return false;
+        }
+        println!("{} is up to date", path.display());
+        return true;
+    }
+
+    match fs::write(path, rendered) {
+        Ok(()) => {
+            println!("Wrote {}", path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("Error: unable to write {}: {}", path.display(), e);
+            false
+        }
+    }
+}
+
+fn load_config(opt: &Opt, base_url: Option<&str>) -> Result<Config, String> {
+    let mut config = Config::load(&opt.config)?;
+    if let Some(base_url) = base_url {
+        config.base_url = Some(base_url.to_string());
+    }
+
+    Ok(config)
+}
+
+fn fetch(days: &[(usize, usize)], opt: &Opt, base_url: Option<&str>) -> usize {
+    let config = match load_config(opt, base_url) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return days.len();
+        }
+    };
+
+    let dir = opt.inputs_dir.as_deref().unwrap_or(Path::new("inputs"));
+    let mut failed = 0;
+
+    for &(year, day) in days {
+        match remote::fetch_input(&config, year, day, dir) {
+            Ok(Fetched::Cached(path)) => {
+                println!("{} Day {:02} cached at {}", year, day, path.display())
             }
-            ReturnSize::Str(ans) => {
-                println!("Day {:02} Part 1: {}", day, ans.0);
-                println!("Day {:02} Part 2: {}", day, ans.1);
+            Ok(Fetched::Downloaded(path)) => {
+                println!("{} Day {:02} downloaded to {}", year, day, path.display())
             }
-            ReturnSize::USIZE(ans) => {
-                println!("Day {:02} Part 1: {}", day, ans.0);
-                println!("Day {:02} Part 2: {}", day, ans.1);
+            Err(e) => {
+                println!("{} Day {:02} FAILED: {}", year, day, e);
+                failed += 1;
             }
         }
-        total_time += elapsed;
+    }
+
+    failed
+}
+
+fn submit(
+    solvers: &[&'static dyn DynSolver],
+    opt: &Opt,
+    (year, day, part): (usize, usize, usize),
+    base_url: Option<&str>,
+    history_path: &Path,
+) -> Result<Verdict, String> {
+    if part != 1 && part != 2 {
+        return Err(format!("invalid part {}, expected 1 or 2", part));
+    }
+    let solver = solvers
+        .iter()
+        .find(|s| s.year() == year && s.day() == day)
+        .ok_or(format!("day {} of {} is not implemented", day, year))?;
+
+    let config = load_config(opt, base_url)?;
+    config.session()?;
 
-        println!("Day {:02} Time D: {}us\n", day, elapsed.as_micros());
-        day += 1;
+    let record = solve_day(*solver, opt, None);
+    if record.status != Status::Ok {
+        return Err(record.error.unwrap_or_default());
     }
+    let answer = match part {
+        1 => record.part1,
+        _ => record.part2,
+    }
+    .ok_or(format!("day {} has no answer for part {}", day, part))?;
+
+    let (verdict, message) =
+        remote::submit_checked(&config, history_path, year, day, part, &answer)?;
 
-    // total time
-    println!("Total Solve Time: {:.5}s", total_time.as_secs_f64());
     println!(
-        "Average Solve Time: {:.5}s",
-        total_time.as_secs_f64() / days_to_run.len() as f64
+        "Day {:02} Part {}: {} is {}",
+        day,
+        part,
+        answer,
+        verdict.as_str()
     );
+    if !message.is_empty() && verdict != Verdict::Correct {
+        println!("{}", message);
+    }
+
+    Ok(verdict)
+}
+
+fn main() {
+    let solvers = adventofcode23::solvers();
+
+    let opt = Opt::from_args();
+    isolate::install_panic_hook();
+
+    let mut years: Vec<usize> = solvers.iter().map(|s| s.year()).collect();
+    years.dedup();
+    let latest_year = years.last().copied().unwrap_or_default();
+
+    if let Some(Command::NewDay { day, title }) = &opt.cmd {
+        if let Err(e) = scaffold::new_day(
+            Path::new("."),
+            opt.year.unwrap_or(latest_year),
+            *day,
+            title.as_deref(),
+            |change| println!("{}", change),
+        ) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(Command::Submit {
+        day,
+        part,
+        base_url,
+        history,
+    }) = &opt.cmd
+    {
+        let puzzle = (opt.year.unwrap_or(latest_year), *day, *part);
+        let result = submit(&solvers, &opt, puzzle, base_url.as_deref(), history);
+        match result {
+            Ok(Verdict::Correct) => return,
+            Ok(_) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let fetching = matches!(opt.cmd, Some(Command::Fetch { .. }));
+    // inputs can be fetched for years that have no solvers yet
+    if let Some(year) = opt.year.filter(|y| !years.contains(y) && !fetching) {
+        eprintln!("Error: no days implemented for {}", year);
+        std::process::exit(1);
+    }
+
+    if opt.list {
+        for solver in solvers.iter() {
+            if opt.year.is_none_or(|y| y == solver.year()) {
+                println!(
+                    "{} Day {:02}: {}",
+                    solver.year(),
+                    solver.day(),
+                    solver.title()
+                );
+            }
+        }
+        return;
+    }
+
+    // the README covers every day solved from the bundled inputs, whatever is selected
+    let picks_input = opt.input.is_some() || opt.inputs_dir.is_some() || opt.part != Part::Both;
+    let selects = opt.year.is_some() || opt.days.is_some() || opt.skip.is_some() || opt.latest;
+    if matches!(opt.cmd, Some(Command::Readme { .. })) && (selects || picks_input) {
+        eprintln!(
+            "Error: readme always covers every day with the bundled inputs, \
+             --year, --days, --skip, --latest, --part, --input and --inputs-dir don't apply"
+        );
+        std::process::exit(1);
+    }
+
+    // picking days only makes sense within one year, the latest unless --year says otherwise.
+    // fetching works on one year too, all of it unless days are picked
+    let picks_days = opt.days.is_some() || opt.skip.is_some() || opt.latest || opt.input.is_some();
+    let year = opt.year.or((picks_days || fetching).then_some(latest_year));
+
+    let selected = match year {
+        None => Ok(solvers.iter().map(|s| (s.year(), s.day())).collect()),
+        Some(year) => {
+            let mut available: Vec<usize> = solvers
+                .iter()
+                .filter(|s| s.year() == year)
+                .map(|s| s.day())
+                .collect();
+            // inputs can be fetched before a day is implemented, every day unless --days
+            // says otherwise
+            if fetching {
+                available = (1..=25).collect();
+            }
+            select::select(&available, opt.days.as_ref(), opt.skip.as_ref(), opt.latest)
+                .and_then(|days| match (&opt.input, days.len()) {
+                    (Some(_), n) if n != 1 => Err(format!(
+                        "--input needs exactly one day, {} selected",
+                        DaySpec::from_days(&days)
+                    )),
+                    _ => Ok(days),
+                })
+                .map(|days| days.into_iter().map(|day| (year, day)).collect())
+        }
+    };
+    let selected: Vec<(usize, usize)> = match selected {
+        Ok(selected) => selected,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let days_to_run: Vec<&'static dyn DynSolver> = solvers
+        .iter()
+        .copied()
+        .filter(|s| selected.contains(&(s.year(), s.day())))
+        .collect();
+
+    if opt.example && (opt.cmd.is_some() || opt.bench.is_some()) {
+        eprintln!("Error: --example only applies to solving, not to subcommands or --bench");
+        std::process::exit(1);
+    }
+
+    let failed = match &opt.cmd {
+        Some(Command::Verify) => usize::from(!verify(&days_to_run, &opt, &opt.answers)),
+        Some(Command::Readme { check, path }) => {
+            usize::from(!write_readme(&solvers, &opt, path, *check))
+        }
+        Some(Command::NewDay { .. } | Command::Submit { .. }) => {
+            unreachable!("handled before solving")
+        }
+        Some(Command::Fetch { base_url }) => fetch(&selected, &opt, base_url.as_deref()),
+        None => match opt.bench {
+            Some(0) => {
+                eprintln!("Error: --bench needs at least one run");
+                1
+            }
+            Some(_) if opt.format != Format::Text => {
+                eprintln!("Error: --bench only reports as text");
+                1
+            }
+            Some(runs) => usize::from(!bench(&days_to_run, &opt, runs)),
+            None if opt.jobs == 0 => {
+                eprintln!("Error: --jobs needs at least one thread");
+                1
+            }
+            None => run(&days_to_run, &opt),
+        },
+    };
+
+    // exit code is the number of failed days
+    std::process::exit(failed.min(255) as i32);
 }
@@ -0,0 +1,265 @@
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+/// The primitive integer types, so the helpers below work at any width
+pub trait Int:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    /// `None` only for the minimum of a signed type
+    fn checked_abs(self) -> Option<Self>;
+    /// Floor of the square root; panics if `self` is negative
+    fn isqrt(self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($abs:expr; $($t:ty)*) => {$(
+        impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                $abs(self)
+            }
+
+            fn isqrt(self) -> Self {
+                <$t>::isqrt(self)
+            }
+        }
+    )*};
+}
+
+impl_int!(Some; u8 u16 u32 u64 u128 usize);
+impl_int!(|n: Self| n.checked_abs(); i8 i16 i32 i64 i128 isize);
+
+/// Greatest common divisor, never negative. Panics if it doesn't fit in `T`, which only
+/// happens for `gcd(T::MIN, 0)`, `gcd(0, T::MIN)` and `gcd(T::MIN, T::MIN)` of a signed type.
+pub fn gcd<T: Int>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        // only `T::MIN % -1` has no remainder that fits, and that remainder is 0
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+
+    a.checked_abs()
+        .unwrap_or_else(|| panic!("gcd of {} does not fit", a))
+}
+
+/// Least common multiple, never negative, or `None` if it overflows `T`
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    // divide first so only the result itself can overflow
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+/// Least common multiple of every number, 1 if there are none
+pub fn lcm_all<T: Int>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |acc, number| lcm(acc, number))
+}
+
+/// Integer square root, rounded down
+pub fn isqrt<T: Int>(n: T) -> T {
+    n.isqrt()
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `m` is positive and coprime to `a`
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base ^ exp % m`. Panics if `m` is 0, like `%` does.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Solve `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair. The moduli don't
+/// have to be coprime. Returns the smallest non-negative `x` along with the lcm of the
+/// moduli, or `None` if a modulus isn't positive, the congruences contradict each other or
+/// the lcm overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, m), &(residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+
+            // x + m * k ≡ residue (mod modulus) has a solution iff g divides the difference.
+            // both x and the reduced residue are non-negative, so the difference can't overflow
+            let (g, inverse, _) = extended_gcd(m, modulus);
+            let diff = residue.rem_euclid(modulus) - x;
+            if diff % g != 0 {
+                return None;
+            }
+
+            let step = modulus / g;
+            let k = (diff / g % step).checked_mul(inverse)?.rem_euclid(step);
+            let lcm = m.checked_mul(step)?.abs();
+            Some((m.checked_mul(k)?.checked_add(x)?.rem_euclid(lcm), lcm))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{crt, extended_gcd, gcd, isqrt, lcm, lcm_all, mod_inverse, mod_pow};
+
+    #[test]
+    fn gcd_lcm_test() {
+        assert_eq!(6, gcd(48u32, 18));
+        assert_eq!(6, gcd(-48i64, 18));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(1, gcd(i8::MIN, -1));
+        assert_eq!(1, gcd(-1, i8::MIN));
+        assert_eq!(Some(144), lcm(48usize, 18));
+        assert_eq!(Some(0), lcm(0, 5));
+        assert_eq!(None, lcm(u8::MAX, 2));
+        assert_eq!(Some(60), lcm_all([3u64, 4, 5, 6]));
+        assert_eq!(None, lcm_all([u64::MAX, u64::MAX - 1]));
+        assert_eq!(Some(1), lcm_all(Vec::<i32>::new()));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn gcd_overflow_test() {
+        gcd(i8::MIN, 0);
+    }
+
+    #[test]
+    fn isqrt_test() {
+        assert_eq!(0, isqrt(0u8));
+        assert_eq!(3, isqrt(15u32));
+        assert_eq!(4, isqrt(16i64));
+        assert_eq!(u64::MAX as u128, isqrt(u128::MAX));
+    }
+
+    #[test]
+    fn modular_test() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!((2, 2), (g, 240 * x + 46 * y));
+
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 8));
+        assert_eq!(None, mod_inverse(3, 0));
+        assert_eq!(None, mod_inverse(3, -7));
+
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(1, mod_pow(u64::MAX, u64::MAX, u64::MAX - 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn mod_pow_zero_test() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // non-coprime moduli
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+        assert_eq!(None, crt(&[(0, i128::MAX), (0, i128::MAX - 1)]));
+        assert_eq!(None, crt(&[(0, i128::MIN)]));
+        assert_eq!(None, crt(&[(1, 3), (0, 0)]));
+        // i128::MIN ≡ 2 (mod 5)
+        assert_eq!(Some((7, 15)), crt(&[(1, 3), (i128::MIN, 5)]));
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_prop(a in 0u32..1000, b in 0u32..1000) {
+            let expected = (1..=a.max(b)).rev().find(|d| a % d == 0 && b % d == 0).unwrap_or(0);
+            prop_assert_eq!(expected, gcd(a, b));
+        }
+
+        #[test]
+        fn crt_prop(congruences in prop::collection::vec((0i128..30, 1i128..12), 0..4)) {
+            let congruences: Vec<(i128, i128)> =
+                congruences.into_iter().map(|(r, m)| (r % m, m)).collect();
+            let period = lcm_all(congruences.iter().map(|&(_, m)| m)).unwrap();
+            let expected = (0..period).find(|x| congruences.iter().all(|&(r, m)| x % m == r));
+
+            prop_assert_eq!(expected.map(|x| (x, period)), crt(&congruences));
+        }
+
+        #[test]
+        fn isqrt_prop(n: u64) {
+            let root = isqrt(n) as u128;
+            prop_assert!(root * root <= n as u128 && (root + 1) * (root + 1) > n as u128);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use serde::Serialize;
+
+/// Allocations made while solving a day
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub count: u64,
+    pub bytes: u64,
+    /// Most bytes held at once, on top of what was already live when measuring started
+    pub peak_bytes: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    // memory freed here but allocated on another thread can push this below zero
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // per thread, so days solved in parallel are counted apart. no destructor and a const
+    // initializer, so touching it from inside the allocator never allocates
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // the thread local is gone while the thread shuts down, those allocations are dropped
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        if allocated > 0 {
+            counters.count += 1;
+            counters.bytes += allocated as u64;
+        }
+        counters.live += allocated as i64 - freed as i64;
+        counters.peak = counters.peak.max(counters.live);
+        cell.set(counters);
+    });
+}
+
+/// The system allocator, counting every allocation made on each thread. Only installed
+/// with the `alloc-stats` feature, so normal runs pay nothing for it.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+/// Run `f` and count what it allocates on this thread, `None` without `alloc-stats`
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let before = COUNTERS.with(|cell| {
+        let mut counters = cell.get();
+        counters.peak = counters.live;
+        cell.set(counters);
+        counters
+    });
+    let value = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let stats = AllocStats {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+        peak_bytes: (after.peak - before.live) as u64,
+    };
+    (value, Some(stats))
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::measure;
+
+    #[test]
+    fn measure_test() {
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            second.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!((2, 1600), (stats.count, stats.bytes));
+        assert_eq!(1000, stats.peak_bytes);
+    }
+
+    #[test]
+    fn baseline_test() {
+        // memory that is already live doesn't count towards the peak
+        let kept = vec![0u8; 4096];
+        let (_, stats) = measure(|| Box::new(1u64));
+
+        assert_eq!(Some(8), stats.map(|stats| stats.peak_bytes));
+        drop(kept);
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Run `work` on every task with up to `jobs` threads. Workers pull tasks off a shared
+/// counter, and results are handed to `on_result` and returned in task order, each as
+/// soon as every task before it is done.
+pub fn run_ordered<T, R, W, F>(tasks: &[T], jobs: usize, work: W, mut on_result: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    F: FnMut(&R),
+{
+    let next_task = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut results = Vec::with_capacity(tasks.len());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let tx = tx.clone();
+            let (next_task, work) = (&next_task, &work);
+            scope.spawn(move || loop {
+                let idx = next_task.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&results.len()) {
+                on_result(&result);
+                results.push(result);
+            }
+        }
+    });
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::run_ordered;
+
+    #[test]
+    fn order_test() {
+        let tasks: Vec<u64> = (0..20).collect();
+        let mut seen = Vec::new();
+
+        // later tasks finish first, but still come out in order
+        let results = run_ordered(
+            &tasks,
+            4,
+            |&n| {
+                thread::sleep(Duration::from_millis(20 - n));
+                n * n
+            },
+            |&square| seen.push(square),
+        );
+
+        let expected: Vec<u64> = tasks.iter().map(|n| n * n).collect();
+        assert_eq!(expected, results);
+        assert_eq!(expected, seen);
+    }
+
+    #[test]
+    fn empty_test() {
+        let results: Vec<u8> = run_ordered(&[] as &[u8], 0, |&n| n, |_| {});
+
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use std::str::FromStr;
+
+use crate::error::{parse_number, ParseError};
+
+/// A line of the input along with its 0-based index, so anything parsed out of it can
+/// report where it went wrong. Every `part` handed to its methods has to be a slice of
+/// `text`, just like with [`ParseError::at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub idx: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Error on `part` of this line
+    pub fn error(&self, part: &str, message: &str) -> ParseError {
+        ParseError::at(self.idx, self.text, part, message)
+    }
+
+    /// Error just past the end of `part`, for things that are missing after it
+    pub fn error_after(&self, part: &str, message: &str) -> ParseError {
+        self.error(&part[part.len()..], message)
+    }
+
+    /// Split `part` around the first `sep`, reporting `message` at the end of `part` if
+    /// there is none
+    pub fn split(
+        &self,
+        part: &'a str,
+        sep: &str,
+        message: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(sep)
+            .ok_or_else(|| self.error_after(part, message))
+    }
+
+    /// Parse `part` as a single number
+    pub fn number<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        parse_number(self.idx, self.text, part)
+    }
+
+    /// Parse the whitespace separated numbers in `part`, signed or unsigned depending on `T`
+    pub fn numbers<T: FromStr>(&self, part: &str) -> Result<Vec<T>, ParseError> {
+        part.split_ascii_whitespace()
+            .map(|n| self.number(n))
+            .collect()
+    }
+
+    /// The values of a `label: values` line, with `label` given including its colon
+    pub fn label(&self, label: &str) -> Result<&'a str, ParseError> {
+        let text = self.text.trim_start();
+        text.strip_prefix(label).map(str::trim).ok_or_else(|| {
+            let found = text.split_ascii_whitespace().next().unwrap_or(text);
+            self.error(found, &format!("expected '{}', found", label))
+        })
+    }
+
+    /// The numbers of a `label: 1 2 3` line
+    pub fn labeled_numbers<T: FromStr>(&self, label: &str) -> Result<Vec<T>, ParseError> {
+        self.numbers(self.label(label)?)
+    }
+
+    /// A `key = (a, b)` line
+    pub fn key_pair(&self) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+        let syntax_error = || self.error(self.text, "expected 'key = (a, b)', found");
+
+        let (key, pair) = self.text.split_once(" = ").ok_or_else(syntax_error)?;
+        let (a, b) = pair
+            .strip_prefix('(')
+            .and_then(|pair| pair.strip_suffix(')'))
+            .and_then(|pair| pair.split_once(", "))
+            .ok_or_else(syntax_error)?;
+
+        Ok((key, (a, b)))
+    }
+}
+
+/// Every line of `input`, blank ones included
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Line { idx, text })
+}
+
+/// Parse every line of `input` with `line`, stopping at the first error
+pub fn each_line<'a, T>(
+    input: &'a str,
+    line: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(input).map(line).collect()
+}
+
+/// The runs of non-blank lines, split wherever one or more blank lines come between them
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+
+    for line in lines(input) {
+        match sections.last_mut() {
+            Some(section) if !line.is_blank() => section.push(line),
+            Some(section) if !section.is_empty() => sections.push(Vec::new()),
+            _ => {}
+        }
+    }
+
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+/// Error just past the last line, for lines or sections that are missing entirely
+pub fn missing(input: &str, message: &str) -> ParseError {
+    ParseError::at_end(input.lines().count(), "", message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{each_line, lines, missing, sections, Line};
+
+    fn line(text: &str) -> Line<'_> {
+        Line { idx: 2, text }
+    }
+
+    #[test]
+    fn numbers_test() {
+        let line = line("Time:   7 -15  30");
+
+        assert_eq!(Ok(vec![7i64, -15, 30]), line.labeled_numbers("Time:"));
+        let err = line.labeled_numbers::<u32>("Time:").unwrap_err();
+        assert_eq!("3:11: expected a number, found '-15'", err.to_string());
+
+        let err = line.label("Distance:").unwrap_err();
+        assert_eq!("3:1: expected 'Distance:', found 'Time:'", err.to_string());
+    }
+
+    #[test]
+    fn split_test() {
+        let line = line("Card 1: 41 48 | 83 86");
+        let (_, numbers) = line.split(line.text, ":", "expected ':'").unwrap();
+
+        assert_eq!((" 41 48 ", " 83 86"), line.split(numbers, "|", "").unwrap());
+        let err = line.split(numbers, ";", "expected ';'").unwrap_err();
+        assert_eq!((3, 22), (err.line, err.column));
+    }
+
+    #[test]
+    fn key_pair_test() {
+        assert_eq!(
+            Ok(("AAA", ("BBB", "CCC"))),
+            line("AAA = (BBB, CCC)").key_pair()
+        );
+
+        for text in ["AAA (BBB, CCC)", "AAA = (BBB CCC)", "AAA = BBB, CCC"] {
+            let err = line(text).key_pair().unwrap_err();
+            assert_eq!((3, 1, text), (err.line, err.column, err.text.as_str()));
+        }
+    }
+
+    #[test]
+    fn sections_test() {
+        let input = "\nseeds: 1 2\n\n\na map:\n1 2 3\n\n";
+        let found = sections(input);
+
+        assert_eq!(2, found.len());
+        assert_eq!(vec![1], found[0].iter().map(|l| l.idx).collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 5],
+            found[1].iter().map(|l| l.idx).collect::<Vec<_>>()
+        );
+        assert!(sections("\n \n").is_empty());
+
+        let err = missing(input, "expected a map");
+        assert_eq!((8, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn each_line_test() {
+        let err = each_line("1 2\n3 x", |line| line.numbers::<u8>(line.text)).unwrap_err();
+
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!(2, lines("a\n\nb").filter(|line| !line.is_blank()).count());
+    }
+}
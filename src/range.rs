@@ -0,0 +1,286 @@
+/// A set of integers kept as sorted, disjoint inclusive `(lo, hi)` ranges. Ranges that
+/// overlap or touch are merged, so two sets are equal exactly when they hold the same
+/// integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// The integers in `lo..=hi`, empty if `lo > hi`
+    pub fn from_range(lo: i64, hi: i64) -> Self {
+        RangeSet::from_iter([(lo, hi)])
+    }
+
+    pub fn ranges(&self) -> &[(i64, i64)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers are in the set
+    pub fn count(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(lo, hi)| hi.abs_diff(lo) + 1)
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|&(lo, _)| lo)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|&(_, hi)| hi)
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        let idx = self.ranges.partition_point(|&(_, hi)| hi < n);
+        self.ranges.get(idx).is_some_and(|&(lo, _)| lo <= n)
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_lo, a_hi)), Some(&(b_lo, b_hi))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            // whichever ends first can't overlap anything further along
+            if a_hi < b_hi {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// The integers in `self` that are not in `other`
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+
+        for &(lo, hi) in self.ranges.iter() {
+            // the start of what is left of `lo..=hi`, none once it is all removed
+            let mut rest = Some(lo);
+            let first = other.ranges.partition_point(|&(_, o_hi)| o_hi < lo);
+
+            for &(o_lo, o_hi) in other.ranges[first..].iter() {
+                let Some(start) = rest.filter(|_| o_lo <= hi) else {
+                    break;
+                };
+                if o_lo > start {
+                    ranges.push((start, o_lo - 1));
+                }
+                rest = o_hi.checked_add(1);
+            }
+
+            if let Some(start) = rest.filter(|&start| start <= hi) {
+                ranges.push((start, hi));
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Every integer moved by `offset`
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        RangeSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|&(lo, hi)| (lo + offset, hi + offset))
+                .collect(),
+        }
+    }
+}
+
+impl FromIterator<(i64, i64)> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        let mut sorted: Vec<(i64, i64)> = iter.into_iter().filter(|&(lo, hi)| lo <= hi).collect();
+        sorted.sort_unstable();
+
+        let mut ranges: Vec<(i64, i64)> = Vec::with_capacity(sorted.len());
+        for (lo, hi) in sorted {
+            match ranges.last_mut() {
+                Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+                _ => ranges.push((lo, hi)),
+            }
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+/// A map that moves pieces of the number line by their own offsets and leaves everything
+/// else where it is. Pieces may come in any order; where they overlap the one added first
+/// wins.
+#[derive(Debug, Clone, Default)]
+pub struct PiecewiseShift {
+    pieces: Vec<(RangeSet, i64)>,
+}
+
+impl PiecewiseShift {
+    pub fn new() -> Self {
+        PiecewiseShift::default()
+    }
+
+    /// Move the integers in `lo..=hi` by `offset`
+    pub fn insert(&mut self, lo: i64, hi: i64, offset: i64) {
+        self.pieces.push((RangeSet::from_range(lo, hi), offset));
+    }
+
+    pub fn apply(&self, n: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(piece, _)| piece.contains(n))
+            .map_or(n, |&(_, offset)| n + offset)
+    }
+
+    /// Where every integer in `set` ends up
+    pub fn apply_set(&self, set: &RangeSet) -> RangeSet {
+        let mut unmoved = set.clone();
+        let mut moved = Vec::new();
+
+        for (piece, offset) in self.pieces.iter() {
+            moved.push(unmoved.intersection(piece).shift(*offset));
+            unmoved = unmoved.difference(piece);
+        }
+
+        moved
+            .iter()
+            .chain([&unmoved])
+            .flat_map(|set| set.ranges.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::{PiecewiseShift, RangeSet};
+
+    // small bounds so ranges overlap, touch and nest often
+    fn range() -> impl Strategy<Value = (i64, i64)> {
+        (-40i64..40, 0i64..20).prop_map(|(lo, len)| (lo, lo + len))
+    }
+
+    fn range_set() -> impl Strategy<Value = RangeSet> {
+        prop::collection::vec(range(), 0..6).prop_map(RangeSet::from_iter)
+    }
+
+    fn brute(set: &RangeSet) -> BTreeSet<i64> {
+        set.ranges().iter().flat_map(|&(lo, hi)| lo..=hi).collect()
+    }
+
+    // sorted, disjoint and never touching
+    fn is_normalized(set: &RangeSet) -> bool {
+        set.ranges().iter().all(|&(lo, hi)| lo <= hi)
+            && set.ranges().windows(2).all(|w| w[0].1 + 1 < w[1].0)
+    }
+
+    #[test]
+    fn range_set_test() {
+        let set = RangeSet::from_iter([(5, 9), (0, 2), (3, 3), (20, 19)]);
+
+        assert_eq!(&[(0, 3), (5, 9)], set.ranges());
+        assert_eq!(9, set.count());
+        assert!(set.contains(3) && !set.contains(4));
+        assert_eq!(
+            &[(0, 0), (9, 9)],
+            set.difference(&RangeSet::from_range(1, 8)).ranges()
+        );
+        assert!(RangeSet::from_range(i64::MAX, i64::MAX)
+            .difference(&RangeSet::from_range(0, i64::MAX))
+            .is_empty());
+    }
+
+    #[test]
+    fn piecewise_shift_test() {
+        let mut map = PiecewiseShift::new();
+        map.insert(98, 99, -48);
+        map.insert(50, 97, 2);
+
+        assert_eq!(81, map.apply(79));
+        assert_eq!(50, map.apply(98));
+        assert_eq!(100, map.apply(100));
+        let seeds = RangeSet::from_range(50, 51).union(&RangeSet::from_range(98, 100));
+        assert_eq!(&[(50, 53), (100, 100)], map.apply_set(&seeds).ranges());
+    }
+
+    proptest! {
+        #[test]
+        fn from_iter_prop(ranges in prop::collection::vec(range(), 0..6)) {
+            let set = RangeSet::from_iter(ranges.iter().copied());
+            let expected: BTreeSet<i64> = ranges.iter().flat_map(|&(lo, hi)| lo..=hi).collect();
+
+            prop_assert!(is_normalized(&set));
+            prop_assert_eq!(expected.len() as u64, set.count());
+            prop_assert_eq!(expected, brute(&set));
+        }
+
+        #[test]
+        fn set_operations_prop(a in range_set(), b in range_set()) {
+            let (x, y) = (brute(&a), brute(&b));
+
+            for (set, expected) in [
+                (a.union(&b), x.union(&y).copied().collect::<BTreeSet<_>>()),
+                (a.intersection(&b), x.intersection(&y).copied().collect()),
+                (a.difference(&b), x.difference(&y).copied().collect()),
+            ] {
+                prop_assert!(is_normalized(&set));
+                prop_assert_eq!(expected, brute(&set));
+            }
+        }
+
+        #[test]
+        fn contains_prop(set in range_set(), n in -50i64..80) {
+            prop_assert_eq!(brute(&set).contains(&n), set.contains(n));
+        }
+
+        #[test]
+        fn shift_prop(set in range_set(), offset in -100i64..100) {
+            let expected: BTreeSet<i64> = brute(&set).iter().map(|n| n + offset).collect();
+
+            prop_assert_eq!(expected, brute(&set.shift(offset)));
+        }
+
+        #[test]
+        fn apply_set_prop(
+            set in range_set(),
+            pieces in prop::collection::vec((range(), -30i64..30), 0..5),
+        ) {
+            let mut map = PiecewiseShift::new();
+            for &((lo, hi), offset) in pieces.iter() {
+                map.insert(lo, hi, offset);
+            }
+            let expected: BTreeSet<i64> = brute(&set).iter().map(|&n| map.apply(n)).collect();
+
+            let mapped = map.apply_set(&set);
+            prop_assert!(is_normalized(&mapped));
+            prop_assert_eq!(expected, brute(&mapped));
+        }
+    }
+}
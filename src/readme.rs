@@ -0,0 +1,132 @@
+use crate::report::{DayRecord, Summary};
+
+const HEADER: &str = "
+![GitHub Workflow Status (with event)](https://img.shields.io/github/actions/workflow/status/callrbx/aoc23/rust.yml)
+
+# Advent of Code
+
+Rust solves for Advent of Code, starting with 2023.
+
+Each day has it's own source file with builtin tests and its own input file.
+
+Hopefully this will help somebody.
+
+## Usage
+Feel free to use as you see fit.
+
+Regenerate this file with `cargo run --release -- readme`.
+
+Run with `cargo run --profile checked` to report any arithmetic that overflows, along with
+the day and operation, instead of letting it wrap.
+
+Build with `--features alloc-stats` to also report how many allocations each day makes, the
+bytes allocated and the most bytes live at once.
+
+## Output
+
+";
+
+fn cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+/// Render the README with a table of answers and timings per year, one row per day
+pub fn render(records: &[DayRecord], summary: &Summary) -> String {
+    let mut out = HEADER.to_string();
+
+    for (i, r) in records.iter().enumerate() {
+        if i == 0 || records[i - 1].year != r.year {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!("### {}\n\n", r.year));
+            out.push_str("| Day | Title | Part 1 | Part 2 | Time |\n");
+            out.push_str("|----:|-------|-------:|-------:|-----:|\n");
+        }
+        out.push_str(&format!(
+            "| [{:02}](src/y{}/day{:02}.rs) | {} | {} | {} | {}us |\n",
+            r.day,
+            r.year,
+            r.day,
+            cell(r.title),
+            cell(r.part1.as_deref().unwrap_or("-")),
+            cell(r.part2.as_deref().unwrap_or("-")),
+            r.total_us as u128
+        ));
+    }
+
+    out.push_str(&format!(
+        "\nTotal Solve Time: {:.5}s\n",
+        summary.total_time_s
+    ));
+    out.push_str(&format!(
+        "Average Solve Time: {:.5}s\n",
+        summary.average_time_s
+    ));
+
+    out
+}
+
+// timings change from run to run, so they are left out when checking for staleness
+fn without_timings(readme: &str) -> Vec<&str> {
+    readme
+        .lines()
+        .filter(|line| !line.contains(" Solve Time: "))
+        .map(|line| {
+            // the time is always the last cell of a table row
+            if line.starts_with('|') {
+                line.trim_end_matches('|')
+                    .rsplit_once('|')
+                    .map_or(line, |(row, _)| row)
+            } else {
+                line
+            }
+        })
+        .collect()
+}
+
+/// Whether `existing` matches a freshly rendered README, ignoring timings
+pub fn is_current(existing: &str, rendered: &str) -> bool {
+    without_timings(existing) == without_timings(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_current, render};
+    use crate::report::{DayRecord, Summary};
+    use std::time::Duration;
+
+    fn records(part2: &str, total_us: f64) -> Vec<DayRecord> {
+        let mut record = DayRecord::new(2023, 7, "Camel Cards");
+        record.part1 = Some("6440".to_string());
+        record.part2 = Some(part2.to_string());
+        record.total_us = total_us;
+        vec![record]
+    }
+
+    #[test]
+    fn render_test() {
+        let records = records("5905", 980.0);
+        let readme = render(&records, &Summary::new(&records, Duration::ZERO));
+
+        assert!(readme.contains("### 2023\n\n| Day | Title"));
+        assert!(
+            readme.contains("| [07](src/y2023/day07.rs) | Camel Cards | 6440 | 5905 | 980us |\n")
+        );
+        assert!(readme.contains("Total Solve Time: 0.00098s\n"));
+    }
+
+    #[test]
+    fn is_current_test() {
+        let committed = records("5905", 980.0);
+        let committed = render(&committed, &Summary::new(&committed, Duration::ZERO));
+
+        let slower = records("5905", 1200.0);
+        let slower = render(&slower, &Summary::new(&slower, Duration::ZERO));
+        assert!(is_current(&committed, &slower));
+
+        let changed = records("5906", 980.0);
+        let changed = render(&changed, &Summary::new(&changed, Duration::ZERO));
+        assert!(!is_current(&committed, &changed));
+    }
+}
@@ -0,0 +1,253 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+use crate::history::{Attempt, History, Verdict};
+use crate::input;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie, wins over the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "github.com/callrbx/aoc23";
+
+/// Settings for talking to the Advent of Code site, read from an `aoc.toml` file:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Load the config file if there is one, then apply `AOC_SESSION` from the environment
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = if path.exists() {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+            toml::from_str(&text)
+                .map_err(|e: toml::de::Error| format!("{}: {}", path.display(), e.message()))?
+        } else {
+            Config::default()
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or(format!(
+            "no session cookie, set {} or `session` in the config file",
+            SESSION_VAR
+        ))
+    }
+
+    pub fn day_url(&self, year: usize, day: usize) -> String {
+        format!("{}/{}/day/{}", self.base_url(), year, day)
+    }
+}
+
+fn request_error(url: &str, e: ureq::Error) -> String {
+    match e {
+        ureq::Error::StatusCode(400) => format!("{} rejected the session cookie", url),
+        ureq::Error::StatusCode(404) => format!("{} not found, is the day unlocked yet?", url),
+        ureq::Error::StatusCode(code) => format!("{} returned status {}", url, code),
+        e => format!("request to {} failed: {}", url, e),
+    }
+}
+
+/// Download the puzzle input for `day` of `year`
+pub fn download_input(config: &Config, year: usize, day: usize) -> Result<String, String> {
+    let url = format!("{}/input", config.day_url(year, day));
+
+    ureq::get(&url)
+        .header("Cookie", &format!("session={}", config.session()?))
+        .header("User-Agent", USER_AGENT)
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map_err(|e| request_error(&url, e))
+}
+
+// the text of the response's <article>, which holds the verdict, with tags dropped
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Judge the page returned for a submitted answer, returning the verdict along with the
+/// site's message, e.g. how long to wait when rate limited
+pub fn parse_response(html: &str) -> (Verdict, String) {
+    let message = article_text(html);
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    };
+
+    (verdict, message)
+}
+
+/// Post `answer` for `part` of `day` in `year` and judge the response
+pub fn submit_answer(
+    config: &Config,
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<(Verdict, String), String> {
+    let url = format!("{}/answer", config.day_url(year, day));
+
+    ureq::post(&url)
+        .header("Cookie", &format!("session={}", config.session()?))
+        .header("User-Agent", USER_AGENT)
+        .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map(|html| parse_response(&html))
+        .map_err(|e| request_error(&url, e))
+}
+
+/// Submit `answer` like [`submit_answer`], but only if the attempts kept in `history_path`
+/// don't already rule it out, and record the verdict there afterwards
+pub fn submit_checked(
+    config: &Config,
+    history_path: &Path,
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<(Verdict, String), String> {
+    config.session()?;
+    let mut history = History::load(history_path)?;
+    history
+        .check(year, day, part, answer)
+        .map_err(|e| format!("not submitting, {}", e))?;
+
+    let (verdict, message) = submit_answer(config, year, day, part, answer)?;
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    history.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        time,
+    });
+    history.save(history_path)?;
+
+    Ok((verdict, message))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Make sure `dir/<year>/dayNN` holds the input for `day`, downloading it only if it is
+/// missing or empty. Inputs never change, so a cached one is never downloaded again.
+pub fn fetch_input(
+    config: &Config,
+    year: usize,
+    day: usize,
+    dir: &Path,
+) -> Result<Fetched, String> {
+    let path = dir.join(input::day_path(year, day));
+
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = download_input(config, year, day)?;
+    let parent = path.parent().unwrap_or(dir);
+    fs::create_dir_all(parent)
+        .and_then(|_| fs::write(&path, input))
+        .map_err(|e| format!("unable to write {}: {}", path.display(), e))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_response, Config};
+    use crate::history::Verdict;
+
+    #[test]
+    fn config_test() {
+        let config: Config = toml::from_str("base_url = \"http://localhost:8080/\"").unwrap();
+
+        assert_eq!("http://localhost:8080/2023/day/5", config.day_url(2023, 5));
+        assert!(Config::default().base_url().starts_with("https://"));
+        assert!(toml::from_str::<Config>("sesion = \"abc\"").is_err());
+    }
+
+    #[test]
+    fn parse_response_test() {
+        let page =
+            |article: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", article);
+
+        let (verdict, _) = parse_response(&page(
+            "That's not the right answer; your answer is too high. <a href=\"/2023/day/5\">[Return]</a>",
+        ));
+        assert_eq!(Verdict::TooHigh, verdict);
+
+        let (verdict, message) = parse_response(&page(
+            "You gave an answer too recently. You have <span>34s</span> left to wait.",
+        ));
+        assert_eq!(Verdict::RateLimited, verdict);
+        assert!(message.ends_with("You have 34s left to wait."));
+
+        assert_eq!(
+            Verdict::Correct,
+            parse_response(&page("That's the right answer!")).0
+        );
+        assert_eq!(Verdict::Unknown, parse_response("<html></html>").0);
+    }
+}
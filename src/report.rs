@@ -0,0 +1,250 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::memory::AllocStats;
+
+/// Output format for solve results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Panic,
+    Overflow,
+    Mismatch,
+    Timeout,
+    Error,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Panic => "panic",
+            Status::Overflow => "overflow",
+            Status::Mismatch => "mismatch",
+            Status::Timeout => "timeout",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// Result of running a single day
+#[derive(Debug, Serialize)]
+pub struct DayRecord {
+    pub year: usize,
+    pub day: usize,
+    /// Name of the example that was solved instead of the real input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<&'static str>,
+    pub title: &'static str,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_us: f64,
+    pub part1_us: f64,
+    pub part2_us: f64,
+    pub total_us: f64,
+    pub status: Status,
+    pub error: Option<String>,
+    pub input_sha256: Option<String>,
+    /// Only measured with the `alloc-stats` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<AllocStats>,
+}
+
+impl DayRecord {
+    pub fn new(year: usize, day: usize, title: &'static str) -> Self {
+        DayRecord {
+            year,
+            day,
+            example: None,
+            title,
+            part1: None,
+            part2: None,
+            parse_us: 0.0,
+            part1_us: 0.0,
+            part2_us: 0.0,
+            total_us: 0.0,
+            status: Status::Ok,
+            error: None,
+            input_sha256: None,
+            allocs: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    /// Solve time summed over every day, regardless of how many ran at once
+    pub total_time_s: f64,
+    pub average_time_s: f64,
+    pub wall_time_s: f64,
+}
+
+impl Summary {
+    pub fn new(records: &[DayRecord], wall_time: Duration) -> Self {
+        let timed: Vec<&DayRecord> = records
+            .iter()
+            .filter(|r| matches!(r.status, Status::Ok | Status::Mismatch))
+            .collect();
+        // fold from +0.0, an empty f64 sum is -0.0 and prints as such
+        let total_time_s = timed.iter().fold(0.0, |sum, r| sum + r.total_us) / 1e6;
+
+        Summary {
+            total_time_s,
+            average_time_s: total_time_s / timed.len().max(1) as f64,
+            wall_time_s: wall_time.as_secs_f64(),
+        }
+    }
+}
+
+pub fn micros(d: Duration) -> f64 {
+    d.as_secs_f64() * 1e6
+}
+
+pub fn sha256_hex(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub fn print_text_record(record: &DayRecord) {
+    let label = match record.example {
+        Some(name) => format!("Day {:02} Example {}", record.day, name),
+        None => format!("Day {:02}", record.day),
+    };
+    let error = record.error.as_deref().unwrap_or("");
+
+    match record.status {
+        Status::Error | Status::Panic | Status::Overflow | Status::Timeout => {
+            println!("{} FAILED: {}\n", label, error)
+        }
+        Status::Ok | Status::Mismatch => {
+            if let Some(ans) = &record.part1 {
+                println!("{} Part 1: {}", label, ans);
+            }
+            if let Some(ans) = &record.part2 {
+                println!("{} Part 2: {}", label, ans);
+            }
+            if record.status == Status::Mismatch {
+                println!("{} Mismatch: {}", label, error);
+            } else if record.example.is_some() {
+                println!("{} Passed", label);
+            }
+            println!("{} Time D: {}us", label, record.total_us as u128);
+            if let Some(allocs) = record.allocs {
+                println!(
+                    "{} Allocs D: {} ({} bytes, {} bytes peak)",
+                    label, allocs.count, allocs.bytes, allocs.peak_bytes
+                );
+            }
+            println!();
+        }
+    }
+}
+
+pub fn print_text_summary(summary: &Summary) {
+    println!("Total Solve Time: {:.5}s", summary.total_time_s);
+    println!("Average Solve Time: {:.5}s", summary.average_time_s);
+    println!("Wall Clock Time: {:.5}s", summary.wall_time_s);
+}
+
+pub fn print_json(records: &[DayRecord], summary: &Summary) {
+    #[derive(Serialize)]
+    struct Report<'a> {
+        days: &'a [DayRecord],
+        summary: &'a Summary,
+    }
+
+    let report = Report {
+        days: records,
+        summary,
+    };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&report).expect("report is always serializable")
+    );
+}
+
+pub fn print_csv(records: &[DayRecord], summary: &Summary) {
+    println!(
+        "year,day,example,title,part1,part2,parse_us,part1_us,part2_us,total_us,status,error,input_sha256"
+    );
+    for r in records {
+        println!(
+            "{},{},{},{},{},{},{:.1},{:.1},{:.1},{:.1},{},{},{}",
+            r.year,
+            r.day,
+            csv_field(r.example.unwrap_or("")),
+            csv_field(r.title),
+            csv_field(r.part1.as_deref().unwrap_or("")),
+            csv_field(r.part2.as_deref().unwrap_or("")),
+            r.parse_us,
+            r.part1_us,
+            r.part2_us,
+            r.total_us,
+            r.status.as_str(),
+            csv_field(r.error.as_deref().unwrap_or("")),
+            r.input_sha256.as_deref().unwrap_or(""),
+        );
+    }
+
+    // summary rows only carry the total column
+    println!("total,,,,,,,,,{:.1},,,", summary.total_time_s * 1e6);
+    println!("average,,,,,,,,,{:.1},,,", summary.average_time_s * 1e6);
+    println!("wall,,,,,,,,,{:.1},,,", summary.wall_time_s * 1e6);
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, sha256_hex};
+
+    #[test]
+    fn csv_field_test() {
+        assert_eq!("Gear Ratios", csv_field("Gear Ratios"));
+        assert_eq!("\"a,b\"", csv_field("a,b"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_field("say \"hi\""));
+    }
+
+    #[test]
+    fn sha256_test() {
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            sha256_hex("")
+        );
+    }
+}
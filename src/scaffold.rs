@@ -0,0 +1,300 @@
+use std::fs;
+use std::path::Path;
+
+use crate::input;
+
+const TEMPLATE: &str = r#"use crate::error::ParseError;
+use crate::solver::Solver;
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(input: &[&str]) -> usize {
+    input.len()
+}
+
+pub fn part2(input: &[&str]) -> usize {
+    input.len()
+}
+
+pub struct Day{N};
+
+impl Solver for Day{N} {
+    const YEAR: usize = {YEAR};
+    const DAY: usize = {N};
+    const TITLE: &'static str = "{TITLE}";
+    const INPUT: &'static str = include_str!("../../inputs/{YEAR}/day{NN}");
+
+    type Parsed<'a> = Vec<&'a str>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<&str>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<&str>) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/{YEAR}/day{NN}-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+}
+"#;
+
+// answers the stub parts give, until the example from the puzzle replaces it
+const EXAMPLE: &str = "part1: 1
+part2: 1
+
+replace with the example input and its answers
+";
+
+/// Source for a new `src/yYYYY/dayNN.rs`
+pub fn day_module(year: usize, day: usize, title: &str) -> String {
+    TEMPLATE
+        .replace("{YEAR}", &year.to_string())
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+        .replace("{TITLE}", &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Source for a new `src/yYYYY/mod.rs` holding just `first_day`
+pub fn year_module(year: usize, first_day: usize) -> String {
+    format!(
+        "//! Advent of Code {}\n\npub mod day{:02};\n",
+        year, first_day
+    )
+}
+
+// the module name of a `pub mod <prefix><number>;` line
+fn numbered_module<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.strip_prefix("pub mod ")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .filter(|name| {
+            name.strip_prefix(prefix)
+                .is_some_and(|n| n.parse::<usize>().is_ok())
+        })
+}
+
+/// `source` with `pub mod <module>;` added next to its siblings, e.g. `day12` among the
+/// other `dayNN` modules, kept in rustfmt order. `None` if it is already declared.
+pub fn declare_module(source: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {};", module);
+    let prefix = module.trim_end_matches(|c: char| c.is_ascii_digit());
+    let is_sibling = |line: &str| numbered_module(line, prefix).is_some();
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return None;
+    }
+
+    let block: Vec<usize> = (0..lines.len()).filter(|&i| is_sibling(lines[i])).collect();
+    let mut modules: Vec<&str> = block.iter().map(|&i| lines[i]).collect();
+    modules.push(&declaration);
+    modules.sort_by_key(|line| numbered_module(line, prefix));
+
+    let start = block.first().copied().unwrap_or(lines.len());
+    lines.retain(|line| !is_sibling(line));
+    lines.splice(start..start, modules);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add day `day` of `year` to the crate at `root`: its module, a placeholder example, an empty
+/// input unless one was already downloaded, and the `mod` declarations for the day and the
+/// year. Every file it creates or changes is reported to `on_change`, relative to `root`.
+pub fn new_day(
+    root: &Path,
+    year: usize,
+    day: usize,
+    title: Option<&str>,
+    mut on_change: impl FnMut(String),
+) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {}, expected 1 to 25", day));
+    }
+
+    let year_dir = Path::new("src").join(format!("y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    let year_mod = year_dir.join("mod.rs");
+    let input = Path::new("inputs").join(input::day_path(year, day));
+    let example = Path::new("inputs/examples")
+        .join(year.to_string())
+        .join(format!("day{:02}-1.txt", day));
+    let lib = Path::new("src/lib.rs");
+
+    if root.join(&module).exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(root.join(path))
+            .map_err(|e| format!("unable to read {}: {}", path.display(), e))
+    };
+    let write = |path: &Path, contents: &str| {
+        let full = root.join(path);
+        full.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&full, contents))
+            .map_err(|e| format!("unable to write {}: {}", path.display(), e))
+    };
+    let title = title.map_or(format!("Day {}", day), String::from);
+
+    write(&module, &day_module(year, day, &title))?;
+    on_change(format!("Created {}", module.display()));
+
+    // keep an example or input that is already there
+    if !root.join(&example).exists() {
+        write(&example, EXAMPLE)?;
+        on_change(format!("Created {}", example.display()));
+    }
+    if !root.join(&input).exists() {
+        write(&input, "")?;
+        on_change(format!("Created {}", input.display()));
+    }
+
+    if root.join(&year_mod).exists() {
+        if let Some(mod_rs) = declare_module(&read(&year_mod)?, &format!("day{:02}", day)) {
+            write(&year_mod, &mod_rs)?;
+            on_change(format!("Registered day {} in {}", day, year_mod.display()));
+        }
+    } else {
+        write(&year_mod, &year_module(year, day))?;
+        on_change(format!("Created {}", year_mod.display()));
+    }
+
+    if let Some(lib_rs) = declare_module(&read(lib)?, &format!("y{}", year)) {
+        write(lib, &lib_rs)?;
+        on_change(format!("Registered year {} in {}", year, lib.display()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{day_module, declare_module, new_day, year_module, EXAMPLE};
+    use crate::example::Example;
+
+    const MOD: &str = "//! Advent of Code 2023
+
+pub mod day01;
+pub mod day02;
+pub mod day11;
+";
+
+    #[test]
+    fn day_module_test() {
+        let source = day_module(2023, 12, "Hot \"Springs\"");
+
+        assert!(source.contains("pub struct Day12;"));
+        assert!(source.contains("const YEAR: usize = 2023;"));
+        assert!(source.contains("const DAY: usize = 12;"));
+        assert!(source.contains("const TITLE: &'static str = \"Hot \\\"Springs\\\"\";"));
+        assert!(source.contains("include_str!(\"../../inputs/2023/day12\")"));
+        assert!(source.contains("include_str!(\"../../inputs/examples/2023/day12-1.txt\")"));
+
+        // the stub parts count the input lines
+        let example = Example::parse(EXAMPLE).unwrap();
+        let lines = example.input.lines().count().to_string();
+        assert_eq!(
+            (Some(&lines), Some(&lines)),
+            (example.part1.as_ref(), example.part2.as_ref())
+        );
+    }
+
+    #[test]
+    fn declare_module_test() {
+        let source = declare_module(MOD, "day03").unwrap();
+
+        assert!(source.contains("pub mod day02;\npub mod day03;\npub mod day11;\n"));
+        assert!(source.starts_with("//! Advent of Code 2023\n\npub mod day01;"));
+        assert_eq!(None, declare_module(&source, "day03"));
+
+        let lib = declare_module("pub mod solver;\n\npub mod y2023;\n", "y2015").unwrap();
+        assert_eq!("pub mod solver;\n\npub mod y2015;\npub mod y2023;\n", lib);
+    }
+
+    #[test]
+    fn year_module_test() {
+        let source = year_module(2024, 1);
+
+        assert_eq!("//! Advent of Code 2024\n\npub mod day01;\n", source);
+        assert!(declare_module(&source, "day02")
+            .unwrap()
+            .ends_with("pub mod day01;\npub mod day02;\n"));
+    }
+
+    #[test]
+    fn new_day_test() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2023")).unwrap();
+        fs::create_dir_all(root.join("inputs/2023")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod solver;\n\npub mod y2023;\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/y2023/mod.rs"), MOD).unwrap();
+        fs::write(root.join("inputs/2023/day12"), "downloaded").unwrap();
+
+        let mut changes = Vec::new();
+        new_day(&root, 2023, 12, None, |change| changes.push(change)).unwrap();
+        new_day(&root, 2024, 1, Some("Title"), |change| changes.push(change)).unwrap();
+        let err = new_day(&root, 2023, 12, None, |_| {}).unwrap_err();
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        let day12 = fs::read_to_string(root.join("src/y2023/day12.rs")).unwrap();
+        let input = fs::read_to_string(root.join("inputs/2023/day12")).unwrap();
+        let example = fs::read_to_string(root.join("inputs/examples/2024/day01-1.txt")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            vec![
+                "Created src/y2023/day12.rs",
+                "Created inputs/examples/2023/day12-1.txt",
+                "Registered day 12 in src/y2023/mod.rs",
+                "Created src/y2024/day01.rs",
+                "Created inputs/examples/2024/day01-1.txt",
+                "Created inputs/2024/day01",
+                "Created src/y2024/mod.rs",
+                "Registered year 2024 in src/lib.rs",
+            ],
+            changes
+        );
+        assert_eq!("src/y2023/day12.rs already exists", err);
+        assert_eq!("pub mod solver;\n\npub mod y2023;\npub mod y2024;\n", lib);
+        assert_eq!(day_module(2023, 12, "Day 12"), day12);
+        assert_eq!("downloaded", input);
+        assert_eq!(EXAMPLE, example);
+        assert!(new_day(&root, 2023, 26, None, |_| {}).is_err());
+    }
+}
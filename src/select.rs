@@ -0,0 +1,170 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A set of days written as a list of days and ranges, e.g. `1-5,8,10`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySpec(Vec<RangeInclusive<usize>>);
+
+impl DaySpec {
+    pub fn contains(&self, day: usize) -> bool {
+        self.0.iter().any(|range| range.contains(&day))
+    }
+
+    /// Every day in the spec, in order and without duplicates
+    pub fn days(&self) -> Vec<usize> {
+        let mut days: Vec<usize> = self.0.iter().cloned().flatten().collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// Collapse a sorted list of days into ranges
+    pub fn from_days(days: &[usize]) -> Self {
+        let mut ranges: Vec<RangeInclusive<usize>> = Vec::new();
+
+        for &day in days {
+            match ranges.last_mut() {
+                Some(range) if *range.end() + 1 == day => *range = *range.start()..=day,
+                _ => ranges.push(day..=day),
+            }
+        }
+
+        DaySpec(ranges)
+    }
+}
+
+fn parse_day(s: &str) -> Result<usize, String> {
+    match s.trim().parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day '{}', expected 1 to 25", s.trim())),
+    }
+}
+
+impl FromStr for DaySpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|part| match part.split_once('-') {
+                Some((lo, hi)) => {
+                    let (lo, hi) = (parse_day(lo)?, parse_day(hi)?);
+                    if lo > hi {
+                        return Err(format!("invalid range '{}', {} is after {}", part, lo, hi));
+                    }
+                    Ok(lo..=hi)
+                }
+                None => parse_day(part).map(|day| day..=day),
+            })
+            .collect::<Result<_, _>>()
+            .map(DaySpec)
+    }
+}
+
+impl fmt::Display for DaySpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .0
+            .iter()
+            .map(|range| {
+                if range.start() == range.end() {
+                    range.start().to_string()
+                } else {
+                    format!("{}-{}", range.start(), range.end())
+                }
+            })
+            .collect();
+
+        write!(f, "{}", parts.join(","))
+    }
+}
+
+/// Pick which of the `available` days (sorted) to run. Without `days` or `latest`
+/// every available day runs; asking for a day that is not implemented is an error.
+pub fn select(
+    available: &[usize],
+    days: Option<&DaySpec>,
+    skip: Option<&DaySpec>,
+    latest: bool,
+) -> Result<Vec<usize>, String> {
+    let implemented = DaySpec::from_days(available);
+
+    let mut selected = match (days, latest) {
+        (Some(days), _) => {
+            let missing: Vec<usize> = days
+                .days()
+                .into_iter()
+                .filter(|day| !available.contains(day))
+                .collect();
+            if !missing.is_empty() {
+                let (noun, verb) = match missing.len() {
+                    1 => ("day", "is"),
+                    _ => ("days", "are"),
+                };
+                return Err(format!(
+                    "{} {} {} not implemented, available days are {}",
+                    noun,
+                    DaySpec::from_days(&missing),
+                    verb,
+                    implemented
+                ));
+            }
+            days.days()
+        }
+        (None, true) => available.last().into_iter().copied().collect(),
+        (None, false) => available.to_vec(),
+    };
+
+    if let Some(skip) = skip {
+        selected.retain(|&day| !skip.contains(day));
+    }
+
+    if selected.is_empty() {
+        return Err(format!(
+            "no days selected, available days are {}",
+            implemented
+        ));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select, DaySpec};
+
+    #[test]
+    fn parse_test() {
+        let spec: DaySpec = "1-5,8,10".parse().unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4, 5, 8, 10], spec.days());
+        assert_eq!("1-5,8,10", spec.to_string());
+        assert!("0".parse::<DaySpec>().is_err());
+        assert!("26".parse::<DaySpec>().is_err());
+        assert!("5-3".parse::<DaySpec>().is_err());
+        assert!("1,,2".parse::<DaySpec>().is_err());
+    }
+
+    #[test]
+    fn select_test() {
+        let available: Vec<usize> = (1..=11).collect();
+        let spec = |s: &str| s.parse::<DaySpec>().unwrap();
+
+        assert_eq!(Ok(available.clone()), select(&available, None, None, false));
+        assert_eq!(Ok(vec![11]), select(&available, None, None, true));
+        assert_eq!(
+            Ok(vec![1, 2, 8]),
+            select(
+                &available,
+                Some(&spec("1-2,8,10")),
+                Some(&spec("10")),
+                false
+            )
+        );
+        assert_eq!(
+            Err("days 12-13 are not implemented, available days are 1-11".to_string()),
+            select(&available, Some(&spec("10-13")), None, false)
+        );
+        assert!(select(&available, Some(&spec("3")), Some(&spec("1-5")), false).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+
+/// A single day's puzzle, split into parsing and the two parts.
+pub trait Solver {
+    /// Event year the puzzle belongs to
+    const YEAR: usize;
+    /// Day of the month the puzzle was released on
+    const DAY: usize;
+    /// Puzzle title as shown on the site
+    const TITLE: &'static str;
+    /// Input embedded at compile time; used when no input is given at runtime
+    const INPUT: &'static str;
+
+    type Parsed<'a>;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    /// Reject input that parses but that `part` can't be solved for, e.g. a node a part
+    /// starts from that isn't there. Run right after `parse`, so the parts can rely on it.
+    fn check(_input: &str, _parsed: &Self::Parsed<'_>, _part: Part) -> Result<(), ParseError> {
+        Ok(())
+    }
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Answer;
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Answer;
+}
+
+/// Which parts of a day to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn runs_part1(self) -> bool {
+        self != Part::Two
+    }
+
+    pub fn runs_part2(self) -> bool {
+        self != Part::One
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("invalid part '{}', expected 1, 2 or both", s)),
+        }
+    }
+}
+
+/// Answers for the parts that were run, with the time spent in each phase
+pub struct Solution {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+impl Solution {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+}
+
+/// Object safe view of a [`Solver`] so every day can live in one registry.
+pub trait DynSolver: Sync {
+    fn year(&self) -> usize;
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+    fn input(&self) -> &'static str;
+    fn solve(&self, input: &str, part: Part) -> Result<Solution, ParseError>;
+}
+
+impl<S: Solver + Sync> DynSolver for S {
+    fn year(&self) -> usize {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<Solution, ParseError> {
+        let start_time = Instant::now();
+        let parsed = S::parse(input)
+            .and_then(|parsed| S::check(input, &parsed, part).map(|_| parsed))
+            .map_err(|e| ParseError { day: S::DAY, ..e })?;
+        let parse_time = start_time.elapsed();
+
+        let start_time = Instant::now();
+        let part1 = part.runs_part1().then(|| S::part1(&parsed).to_string());
+        let part1_time = start_time.elapsed();
+
+        let start_time = Instant::now();
+        let part2 = part.runs_part2().then(|| S::part2(&parsed).to_string());
+        let part2_time = start_time.elapsed();
+
+        Ok(Solution {
+            part1,
+            part2,
+            parse_time,
+            part1_time,
+            part2_time,
+        })
+    }
+}
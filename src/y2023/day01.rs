@@ -0,0 +1,114 @@
+use crate::error::ParseError;
+use crate::solver::Solver;
+
+pub fn extract_digits_from_line(line: &str) -> Option<(char, char)> {
+    let digits: Vec<char> = line.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        None
+    } else {
+        Some((digits[0], *digits.last().unwrap()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}
+
+pub fn part1(input: &[String]) -> u32 {
+    let results: Vec<u32> = input
+        .iter()
+        .map(|line| {
+            if let Some((first_digit, last_digit)) = extract_digits_from_line(line) {
+                let x: u32 = format!("{}{}", first_digit, last_digit).parse().unwrap();
+                x
+            } else {
+                0
+            }
+        })
+        .collect();
+
+    results.iter().sum()
+}
+
+pub fn part2(input: &[String]) -> u32 {
+    // Account for weird overlap of last/first chars
+    // still matches the first digit
+    let mapping = [
+        ("one", "on1e"),
+        ("two", "tw2o"),
+        ("three", "thre3e"),
+        ("four", "fou4r"),
+        ("five", "fiv5e"),
+        ("six", "si6x"),
+        ("seven", "seve7n"),
+        ("eight", "eigh8t"),
+        ("nine", "nin9e"),
+    ];
+
+    let parsed_lines: Vec<String> = input
+        .iter()
+        .map(|line| {
+            let mut mod_line = line.clone();
+            for (spelled, num) in &mapping {
+                mod_line = mod_line.replace(spelled, num);
+            }
+            mod_line
+        })
+        .collect();
+
+    part1(&parsed_lines)
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day01");
+
+    type Parsed<'a> = Vec<String>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<String>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE1: &str = include_str!("../../inputs/examples/2023/day01-1.txt");
+    const EXAMPLE2: &str = include_str!("../../inputs/examples/2023/day01-2.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE1).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE2).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+
+        // the last digit can overlap the word before it
+        assert_eq!(82, part2(&["8eightwo".to_string()]));
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp;
+
+use crate::error::ParseError;
+use crate::math::Int;
+use crate::parse::{each_line, Line};
+use crate::solver::Solver;
+
+const CUBE_CONFIG: (u32, u32, u32) = (12, 13, 14);
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub mins: (u32, u32, u32),
+    pub valid: bool,
+}
+
+impl Game {
+    pub fn new(line: Line) -> Result<Self, ParseError> {
+        let mut ng = Game {
+            id: 0,
+            mins: (0, 0, 0),
+            valid: true,
+        };
+
+        let (id, rounds) = line.split(line.text, ":", "expected ':' after game id")?;
+        // strip past "Game "
+        let id = id
+            .strip_prefix("Game ")
+            .ok_or_else(|| line.error(id, "expected 'Game <id>', found"))?;
+        ng.id = line.number(id)?;
+
+        for round in rounds.split(';') {
+            let mut cv: (u32, u32, u32) = (0, 0, 0);
+            for config in round.split(',') {
+                let config = config.trim();
+                let (amt, col) = line.split(config, " ", "expected a colour after the amount")?;
+                let num: u32 = line.number(amt)?;
+                match col {
+                    "red" => cv.0 = num,
+                    "green" => cv.1 = num,
+                    "blue" => cv.2 = num,
+                    _ => return Err(line.error(col, "unknown colour")),
+                }
+            }
+
+            // recaluculate mins
+            ng.mins = (
+                cmp::max(ng.mins.0, cv.0),
+                cmp::max(ng.mins.1, cv.1),
+                cmp::max(ng.mins.2, cv.2),
+            );
+
+            // do not revalidate an invalid game
+            ng.valid &= cv.0 <= CUBE_CONFIG.0 && cv.1 <= CUBE_CONFIG.1 && cv.2 <= CUBE_CONFIG.2;
+        }
+
+        Ok(ng)
+    }
+
+    /// The product of the fewest cubes of each colour, in `T` so a wrap is caught
+    pub fn power<T: Int + From<u32>>(&self) -> T {
+        T::from(self.mins.0) * T::from(self.mins.1) * T::from(self.mins.2)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    each_line(input, Game::new)
+}
+
+pub fn part1<T: Int + From<u32>>(games: &[Game]) -> T {
+    games
+        .iter()
+        .filter(|game| game.valid)
+        .map(|game| T::from(game.id))
+        .sum()
+}
+
+pub fn part2<T: Int + From<u32>>(games: &[Game]) -> T {
+    games.iter().map(Game::power).sum()
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day02");
+
+    type Parsed<'a> = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(games: &Vec<Game>) -> u64 {
+        part1(games)
+    }
+
+    fn part2(games: &Vec<Game>) -> u64 {
+        part2(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day02-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue, x green").unwrap_err();
+
+        assert_eq!((2, 17), (err.line, err.column));
+        assert_eq!("expected a number, found", err.message);
+    }
+
+    // only builds with overflow checks catch the wrap
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn overflow_test() {
+        let games = parse("Game 1: 2000 red, 2000 green, 2000 blue").unwrap();
+
+        assert_eq!(8_000_000_000, part2::<u64>(&games));
+        part2::<u32>(&games);
+    }
+}
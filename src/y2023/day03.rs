@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::math::Int;
+use crate::solver::Solver;
+
+/// Where the number covering `pos` starts, along with its value
+pub fn number_at<T: Int + From<u32>>(grid: &Grid<char>, (row, col): Pos) -> Option<(Pos, T)> {
+    let cells = grid.row(row);
+    if !cells[col].is_ascii_digit() {
+        return None;
+    }
+
+    let start = cells[..col]
+        .iter()
+        .rposition(|c| !c.is_ascii_digit())
+        .map_or(0, |i| i + 1);
+    let value = cells[start..]
+        .iter()
+        .map_while(|c| c.to_digit(10))
+        .fold(T::ZERO, |n, digit| n * T::from(10) + T::from(digit));
+
+    Some(((row, start), value))
+}
+
+// numbers are told apart by where they start, so equal numbers next to each other count twice
+pub fn adjacent_numbers<T: Int + From<u32>>(grid: &Grid<char>, pos: Pos) -> Vec<T> {
+    let mut starts = HashSet::new();
+
+    grid.neighbors8(pos)
+        .filter_map(|neighbor| number_at(grid, neighbor))
+        .filter(|&(start, _)| starts.insert(start))
+        .map(|(_, value)| value)
+        .collect()
+}
+
+pub fn symbols(grid: &Grid<char>) -> impl Iterator<Item = (Pos, char)> + '_ {
+    grid.iter()
+        .filter(|&(_, &val)| !val.is_ascii_digit() && val != '.')
+        .map(|(pos, &val)| (pos, val))
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some)
+}
+
+pub fn part1<T: Int + From<u32>>(grid: &Grid<char>) -> T {
+    symbols(grid)
+        .flat_map(|(pos, _)| adjacent_numbers::<T>(grid, pos))
+        .sum()
+}
+
+// the numbers, ratios and sums are all in `T`, which has to be wide enough for each
+pub fn part2<T: Int + From<u32>>(grid: &Grid<char>) -> T {
+    symbols(grid)
+        .filter(|&(_, val)| val == '*')
+        .map(|(pos, _)| adjacent_numbers::<T>(grid, pos))
+        .filter(|gears| gears.len() == 2)
+        .map(|gears| gears[0] * gears[1])
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day03");
+
+    type Parsed<'a> = Grid<char>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> u64 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Grid<char>) -> u64 {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day03-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn equal_numbers_test() {
+        // both 23s touch the symbol
+        assert_eq!(46, part1::<u32>(&parse("...#...\n.23.23.").unwrap()));
+    }
+
+    #[test]
+    fn wide_number_test() {
+        let grid = parse("4294967296*2").unwrap();
+
+        assert_eq!(4_294_967_298, part1::<u64>(&grid));
+        assert_eq!(8_589_934_592, part2::<u64>(&grid));
+    }
+
+    // only builds with overflow checks catch the wrap
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn overflow_test() {
+        let grid = parse("99999*99999").unwrap();
+
+        assert_eq!(9_999_800_001, part2::<u64>(&grid));
+        part2::<u32>(&grid);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error::ParseError;
+use crate::math::Int;
+use crate::parse::{each_line, Line};
+use crate::solver::Solver;
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub matches: u32,
+}
+
+impl Card {
+    pub fn new(line: Line) -> Result<Self, ParseError> {
+        let (_, rem) = line.split(line.text, ":", "expected ':' after card id")?;
+        let (winning_str, showing_str) = line.split(
+            rem,
+            "|",
+            "expected '|' between the winning and showing numbers",
+        )?;
+
+        let winning: Vec<u32> = line.numbers(winning_str)?;
+        let showing: Vec<u32> = line.numbers(showing_str)?;
+
+        let matches = showing.iter().filter(|x| winning.contains(x)).count() as u32;
+
+        Ok(Card { matches })
+    }
+
+    /// 1 point for the first match, doubled for every match after it. Doubles by adding
+    /// rather than shifting, so a value too big for `T` trips overflow checks.
+    pub fn value<T: Int>(&self) -> T {
+        if self.matches == 0 {
+            return T::ZERO;
+        }
+        (1..self.matches).fold(T::ONE, |value, _| value + value)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    each_line(input, Card::new)
+}
+
+pub fn part1<T: Int>(cards: &[Card]) -> T {
+    cards.iter().map(Card::value).sum()
+}
+
+// instance counts roughly double with every winning card, so they are kept in `T`
+pub fn part2<T: Int>(cards: &[Card]) -> T {
+    let mut card_instances = vec![T::ONE; cards.len()]; // start with 1 instance of each card
+
+    // every instance of a card wins one more instance of each of the following cards
+    for (i, card) in cards.iter().enumerate() {
+        let instances = card_instances[i];
+        let end = (i + 1 + card.matches as usize).min(cards.len());
+        for next_card_instances in card_instances[i + 1..end].iter_mut() {
+            *next_card_instances += instances;
+        }
+    }
+
+    card_instances.into_iter().sum()
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Scratchcards";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day04");
+
+    type Parsed<'a> = Vec<Card>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cards: &Vec<Card>) -> u64 {
+        part1(cards)
+    }
+
+    fn part2(cards: &Vec<Card>) -> u64 {
+        part2(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, Card};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day04-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2::<u32>(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn value_test() {
+        assert_eq!(0, Card { matches: 0 }.value::<u8>());
+        assert_eq!(8, Card { matches: 4 }.value::<u8>());
+        assert_eq!(1 << 39, Card { matches: 40 }.value::<u64>());
+    }
+
+    // only builds with overflow checks catch the wrap
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn value_overflow_test() {
+        Card { matches: 33 }.value::<u32>();
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("Card 1: 41 48 | 83 86\nCard 2 13 32 | 61 30").unwrap_err();
+        assert_eq!("2:21: expected ':' after card id", err.to_string());
+
+        let err = parse("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!(
+            "1:20: expected '|' between the winning and showing numbers",
+            err.to_string()
+        );
+
+        let err = parse("Card 1: 41 48 | 83 -86").unwrap_err();
+        assert_eq!("1:20: expected a number, found '-86'", err.to_string());
+    }
+}
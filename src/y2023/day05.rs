@@ -0,0 +1,182 @@
+use crate::error::ParseError;
+use crate::parse::{lines, missing, sections, Line};
+use crate::range::{PiecewiseShift, RangeSet};
+use crate::solver::{Part, Solver};
+
+// obvious approach - fast for p1, slow for p2
+/*
+fn map_number(num: i64, mapping: &Vec<(i64, i64, i64)>) -> i64 {
+    for &(dest_start, source_start, length) in mapping {
+        if num >= source_start && num < source_start + length {
+            return dest_start + (num - source_start);
+        }
+    }
+    num
+}
+
+fn trace_seed(seed: i64, mappings: &Vec<Vec<(i64, i64, i64)>>) -> i64 {
+    let mut current_value = seed;
+    for mapping in mappings {
+        current_value = map_number(current_value, mapping);
+    }
+    current_value
+}
+*/
+
+fn parse_entry(line: Line) -> Result<(i64, i64, i64), ParseError> {
+    match line.numbers(line.text)?[..] {
+        [dest_start, source_start, length] => Ok((dest_start, source_start, length)),
+        _ => Err(line.error(line.text, "expected '<dest> <source> <length>', found")),
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<PiecewiseShift>,
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let sections = sections(input);
+    let (seeds, maps) = sections
+        .split_first()
+        .ok_or_else(|| missing(input, "expected a 'seeds:' line"))?;
+
+    let seeds = match seeds[..] {
+        [line] => line.labeled_numbers("seeds:")?,
+        _ => return Err(seeds[1].error(seeds[1].text, "expected a blank line before")),
+    };
+
+    let maps = maps
+        .iter()
+        .map(|section| {
+            let header = section[0];
+            if !header.text.ends_with("map:") {
+                return Err(header.error(header.text, "expected a map header, found"));
+            }
+
+            let mut map = PiecewiseShift::new();
+            for &line in section[1..].iter() {
+                let (dest_start, source_start, length) = parse_entry(line)?;
+                map.insert(
+                    source_start,
+                    source_start + length - 1,
+                    dest_start - source_start,
+                );
+            }
+            Ok(map)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn lowest_location(seeds: &RangeSet, maps: &[PiecewiseShift]) -> i64 {
+    maps.iter()
+        .fold(seeds.clone(), |ranges, map| map.apply_set(&ranges))
+        .min()
+        .unwrap_or(i64::MAX)
+}
+
+pub fn part1(almanac: &Almanac) -> i64 {
+    let seeds: RangeSet = almanac.seeds.iter().map(|&seed| (seed, seed)).collect();
+
+    lowest_location(&seeds, &almanac.maps)
+}
+
+pub fn part2(almanac: &Almanac) -> i64 {
+    // seeds come in (start, length) pairs, `check` makes sure none is left over
+    let seeds: RangeSet = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[0] + pair[1] - 1))
+        .collect();
+
+    lowest_location(&seeds, &almanac.maps)
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day05");
+
+    type Parsed<'a> = Almanac;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        parse(input)
+    }
+
+    // part 1 takes any number of seeds, part 2 needs a length for each start
+    fn check(input: &str, almanac: &Almanac, part: Part) -> Result<(), ParseError> {
+        if !part.runs_part2() || almanac.seeds.len().is_multiple_of(2) {
+            return Ok(());
+        }
+
+        // the seeds line is the first one that parsed
+        let line = lines(input).find(|line| !line.is_blank()).unwrap();
+        let start = line.text.split_ascii_whitespace().last().unwrap();
+        Err(line.error(start, "expected a range length after"))
+    }
+
+    fn part1(almanac: &Almanac) -> i64 {
+        part1(almanac)
+    }
+
+    fn part2(almanac: &Almanac) -> i64 {
+        part2(almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, Day5};
+    use crate::example::Example;
+    use crate::solver::{DynSolver, Part};
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day05-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert_eq!(
+            "4:1: expected '<dest> <source> <length>', found '50 98'",
+            err.to_string()
+        );
+
+        let err = parse("seeds: 79 14\n\n50 98 2").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+        assert!(parse("\n").is_err());
+    }
+
+    #[test]
+    fn check_test() {
+        let input = "\nseeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n";
+
+        let err = Day5.solve(input, Part::Two).err().unwrap();
+        assert_eq!("2:14: expected a range length after '55'", err.to_string());
+        assert_eq!(5, err.day);
+        assert!(Day5.solve(input, Part::One).is_ok());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::ParseError;
+use crate::math::isqrt;
+use crate::parse::{lines, missing, Line};
+use crate::solver::Solver;
+
+pub fn calculate_ways(time: u128, distance: u128) -> u128 {
+    // holding for h wins when h * (time - h) > distance, which is symmetric around time / 2
+    let wins = |hold: u128| hold * (time - hold) > distance;
+    let Some(discriminant) = (time * time).checked_sub(4 * distance) else {
+        return 0;
+    };
+
+    // the smaller root of the quadratic, nudged onto the first winning hold time
+    let mut first = (time - isqrt(discriminant).min(time)) / 2;
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    while first <= time / 2 && !wins(first) {
+        first += 1;
+    }
+
+    if first > time / 2 {
+        0
+    } else {
+        time - 2 * first + 1
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    pub times: Vec<u128>,
+    pub distances: Vec<u128>,
+    // part 2 reads each line as a single number, ignoring the spaces
+    pub time: u128,
+    pub distance: u128,
+}
+
+fn parse_line(line: Line, label: &str) -> Result<(Vec<u128>, u128), ParseError> {
+    let values = line.label(label)?;
+    let numbers = line.numbers(values)?;
+    let joined = values.split_whitespace().collect::<String>();
+    let combined = joined
+        .parse()
+        .map_err(|_| line.error(values, "number too large in"))?;
+
+    Ok((numbers, combined))
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let mut lines = lines(input).filter(|line| !line.is_blank());
+
+    let line = lines
+        .next()
+        .ok_or_else(|| missing(input, "expected 'Time:' line"))?;
+    let (times, time) = parse_line(line, "Time:")?;
+
+    let line = lines
+        .next()
+        .ok_or_else(|| missing(input, "expected 'Distance:' line"))?;
+    let (distances, distance) = parse_line(line, "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(line.error(
+            line.text.trim(),
+            "number of times and distances do not match in",
+        ));
+    }
+
+    Ok(Races {
+        times,
+        distances,
+        time,
+        distance,
+    })
+}
+
+pub fn part1(races: &Races) -> u128 {
+    races
+        .times
+        .iter()
+        .zip(races.distances.iter())
+        .map(|(&time, &distance)| calculate_ways(time, distance))
+        .product()
+}
+
+pub fn part2(races: &Races) -> u128 {
+    calculate_ways(races.time, races.distance)
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Wait For It";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day06");
+
+    type Parsed<'a> = Races;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        parse(input)
+    }
+
+    fn part1(races: &Races) -> u128 {
+        part1(races)
+    }
+
+    fn part2(races: &Races) -> u128 {
+        part2(races)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_ways, parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day06-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+
+        assert_eq!((2, 15), (err.line, err.column));
+    }
+
+    #[test]
+    fn calculate_ways_test() {
+        for time in 0..40 {
+            for distance in 0..400 {
+                let expected = (0..=time).filter(|h| h * (time - h) > distance).count();
+                assert_eq!(expected as u128, calculate_ways(time, distance));
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::ReturnSize;
+use crate::error::ParseError;
+use crate::parse::each_line;
+use crate::solver::Solver;
 
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -121,7 +123,7 @@ impl Hand {
             }
         }
 
-        let mut counts: Vec<_> = counts.into_iter().map(|(_card, count)| count).collect();
+        let mut counts: Vec<_> = counts.into_values().collect();
         counts.sort_unstable_by(|a, b| b.cmp(a));
 
         match counts.as_slice() {
@@ -135,13 +137,12 @@ impl Hand {
         }
     }
 
-    fn sort_weak2strong(hands: &mut Vec<Hand>) {
+    fn sort_weak2strong(hands: &mut [Hand]) {
         // reverse; greater value hand comes first
         hands.sort_by(|a, b| b.compare(a));
     }
 
-    fn new(line: &str, jokers: bool) -> Self {
-        let (cards, bid) = line.split_once(" ").expect("Failed to parse hand");
+    fn new(cards: &str, bid: usize, jokers: bool) -> Self {
         let mut cards = cards.to_string();
 
         // rewrite jokers to wildcard mapping if enabled
@@ -152,74 +153,122 @@ impl Hand {
         Self {
             cards: cards.chars().collect(),
             hand_type: Hand::get_type(cards, jokers),
-            bid: bid.parse().expect("Failed to parse bid"),
+            bid,
         }
     }
 }
 
-fn part1(input: &str) -> usize {
-    let mut hands: Vec<Hand> = input
-        .split("\n")
-        .map(|line| Hand::new(line, false))
-        .collect();
-    Hand::sort_weak2strong(&mut hands);
+/// A hand of cards as written in the input, with its bid
+#[derive(Debug)]
+pub struct Bid<'a> {
+    pub cards: &'a str,
+    pub bid: usize,
+}
 
-    return hands
-        .iter()
-        .enumerate()
-        .map(|(place, hand)| hand.bid * (place + 1))
-        .sum();
+pub fn parse(input: &str) -> Result<Vec<Bid<'_>>, ParseError> {
+    each_line(input, |line| {
+        let (cards, bid) = line.split(line.text, " ", "expected bid after hand")?;
+
+        if cards.len() != 5 || !cards.chars().all(|c| "23456789TJQKA".contains(c)) {
+            return Err(line.error(cards, "expected a hand of 5 cards, found"));
+        }
+
+        Ok(Bid {
+            cards,
+            bid: line.number(bid)?,
+        })
+    })
 }
 
-fn part2(input: &str) -> usize {
-    let mut hands: Vec<Hand> = input
-        .split("\n")
-        .map(|line| Hand::new(line, true))
+pub fn total_winnings(bids: &[Bid], jokers: bool) -> usize {
+    let mut hands: Vec<Hand> = bids
+        .iter()
+        .map(|b| Hand::new(b.cards, b.bid, jokers))
         .collect();
     Hand::sort_weak2strong(&mut hands);
 
-    return hands
+    hands
         .iter()
         .enumerate()
         .map(|(place, hand)| hand.bid * (place + 1))
-        .sum();
+        .sum()
 }
 
-pub fn solve_day() -> ReturnSize {
-    let input = include_str!("../inputs/day7");
+pub fn part1(bids: &[Bid]) -> usize {
+    total_winnings(bids, false)
+}
 
-    return ReturnSize::USIZE((part1(&input), part2(&input)));
+pub fn part2(bids: &[Bid]) -> usize {
+    total_winnings(bids, true)
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Camel Cards";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day07");
+
+    type Parsed<'a> = Vec<Bid<'a>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Bid<'_>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(bids: &Vec<Bid>) -> usize {
+        part1(bids)
+    }
+
+    fn part2(bids: &Vec<Bid>) -> usize {
+        part2(bids)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{part1, part2, Hand};
+    use super::{parse, part1, part2, Hand};
+    use crate::example::Example;
 
-    const INPUT: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day07-1.txt");
 
     #[test]
     fn part1_test() {
-        assert_eq!(6440, part1(&INPUT));
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
     }
 
     #[test]
     fn part2_test() {
-        assert_eq!(5905, part2(&INPUT));
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
     }
 
     #[test]
     fn wildcard_sort_test() {
-        let h1 = Hand::new("JKKK2 1", true);
-        let h2 = Hand::new("QQQQ2 1", true);
+        let h1 = Hand::new("JKKK2", 1, true);
+        let h2 = Hand::new("QQQQ2", 1, true);
         let mut hands = vec![h2, h1]; // should sort out
         Hand::sort_weak2strong(&mut hands);
         assert_eq!(
-            vec![Hand::new("JKKK2 1", true), Hand::new("QQQQ2 1", true)],
+            vec![Hand::new("JKKK2", 1, true), Hand::new("QQQQ2", 1, true)],
             hands
         );
     }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("32T3K 765\nT55J5").unwrap_err();
+        assert_eq!("2:6: expected bid after hand", err.to_string());
+
+        let err = parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+    }
 }
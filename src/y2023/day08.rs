@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::isolate::overflowed;
+use crate::math::lcm_all;
+use crate::parse::{lines, missing};
+use crate::solver::{Part, Solver};
+
+// directions and node -> (left, right) lookup
+pub type Network<'a> = (&'a str, HashMap<&'a str, (&'a str, &'a str)>);
+
+pub fn steps_to_node(
+    start: &str,
+    end: &str,
+    directions: &str,
+    map: &HashMap<&str, (&str, &str)>,
+) -> usize {
+    let mut current_node = start;
+    let mut steps = 0;
+
+    for direction in directions.chars().cycle() {
+        steps += 1;
+        current_node = match direction {
+            'L' => map[current_node].0,
+            'R' => map[current_node].1,
+            _ => panic!("Invalid direction"),
+        };
+
+        if current_node == end {
+            break;
+        }
+    }
+
+    steps
+}
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let mut lines = lines(input).filter(|line| !line.is_blank());
+
+    let directions = lines
+        .next()
+        .ok_or_else(|| missing(input, "expected directions"))?;
+    let invalid = directions
+        .text
+        .char_indices()
+        .find(|&(_, c)| c != 'L' && c != 'R');
+    if let Some((pos, c)) = invalid {
+        let text = directions.text;
+        return Err(directions.error(&text[pos..pos + c.len_utf8()], "invalid direction"));
+    }
+
+    let nodes = lines
+        .map(|line| Ok((line, line.key_pair()?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let map: HashMap<_, _> = nodes.iter().map(|&(_, node)| node).collect();
+
+    // every path has to lead somewhere, otherwise walking the network would panic
+    for (line, (_, (left, right))) in nodes {
+        if let Some(missing) = [left, right].into_iter().find(|n| !map.contains_key(n)) {
+            return Err(line.error(missing, "unknown node"));
+        }
+    }
+
+    Ok((directions.text, map))
+}
+
+pub fn part1((directions, map): &Network) -> usize {
+    steps_to_node("AAA", "ZZZ", directions, map)
+}
+
+pub fn steps_to_z(start: &str, directions: &str, map: &HashMap<&str, (&str, &str)>) -> usize {
+    let mut current_node = start;
+    let mut steps = 0;
+
+    for direction in directions.chars().cycle() {
+        steps += 1;
+        current_node = match direction {
+            'L' => map[current_node].0,
+            'R' => map[current_node].1,
+            _ => panic!("Invalid direction"),
+        };
+
+        if current_node.ends_with('Z') {
+            break;
+        }
+    }
+
+    steps
+}
+
+pub fn part2((directions, map): &Network) -> usize {
+    let start_nodes: Vec<&str> = map
+        .keys()
+        .filter(|&node| node.ends_with('A'))
+        .cloned()
+        .collect();
+
+    let min_steps: Vec<usize> = start_nodes
+        .iter()
+        .map(|start| steps_to_z(start, directions, map))
+        .collect();
+
+    lcm_all(min_steps).unwrap_or_else(|| overflowed("lcm of the step counts"))
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day08");
+
+    type Parsed<'a> = Network<'a>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+        parse(input)
+    }
+
+    // part 2 starts from every node ending in A, but part 1 needs these two exactly
+    fn check(input: &str, (_, map): &Network, part: Part) -> Result<(), ParseError> {
+        match ["AAA", "ZZZ"]
+            .into_iter()
+            .find(|node| !map.contains_key(node))
+        {
+            Some(node) if part.runs_part1() => Err(missing(
+                input,
+                &format!("part 1 needs a node '{}', none found", node),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn part1(network: &Network) -> usize {
+        part1(network)
+    }
+
+    fn part2(network: &Network) -> usize {
+        part2(network)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, Day8};
+    use crate::example::Example;
+    use crate::solver::{DynSolver, Part};
+
+    const EXAMPLE1: &str = include_str!("../../inputs/examples/2023/day08-1.txt");
+    const EXAMPLE2: &str = include_str!("../../inputs/examples/2023/day08-2.txt");
+    const EXAMPLE3: &str = include_str!("../../inputs/examples/2023/day08-3.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE1).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+
+        let example = Example::parse(EXAMPLE2).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE3).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("RLX\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!((1, 3), (err.line, err.column));
+
+        let err = parse("RLé\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!("1:3: invalid direction 'é'", err.to_string());
+
+        let err = parse("RL\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!("3:8: unknown node 'BBB'", err.to_string());
+    }
+
+    #[test]
+    fn check_test() {
+        let input = "LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)";
+
+        let err = Day8.solve(input, Part::Both).err().unwrap();
+        assert_eq!(
+            "5:1: part 1 needs a node 'AAA', none found",
+            err.to_string()
+        );
+        assert_eq!(8, err.day);
+        assert!(Day8.solve(input, Part::Two).is_ok());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::ParseError;
+use crate::parse::each_line;
+use crate::solver::Solver;
+
+pub fn differences(seq: &[i64]) -> Vec<i64> {
+    seq.windows(2).map(|window| window[1] - window[0]).collect()
+}
+
+pub fn differences_prev(seq: &[i64]) -> Vec<i64> {
+    seq.windows(2).map(|window| window[0] - window[1]).collect()
+}
+
+pub fn extrapolate_next_value(base: &[i64]) -> i64 {
+    let mut sequences = vec![base.to_vec()];
+
+    loop {
+        let next = differences(sequences.last().unwrap());
+        if next.iter().all(|&x| x == 0) {
+            break;
+        }
+        sequences.push(next);
+    }
+
+    sequences.iter().map(|s| s.last().unwrap()).sum()
+}
+
+pub fn extrapolate_prev_value(base: &[i64]) -> i64 {
+    let mut sequences = vec![base.to_vec()];
+
+    loop {
+        let prev = differences_prev(sequences.first().unwrap());
+        if prev.iter().all(|&x| x == 0) {
+            break;
+        }
+        sequences.insert(0, prev);
+    }
+
+    sequences.iter().map(|s| s.first().unwrap()).sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    each_line(input, |line| {
+        if line.is_blank() {
+            return Err(line.error_after(line.text, "expected a history of numbers"));
+        }
+        line.numbers(line.text)
+    })
+}
+
+pub fn part1(histories: &[Vec<i64>]) -> i64 {
+    histories.iter().map(|h| extrapolate_next_value(h)).sum()
+}
+
+pub fn part2(histories: &[Vec<i64>]) -> i64 {
+    histories.iter().map(|h| extrapolate_prev_value(h)).sum()
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day09");
+
+    type Parsed<'a> = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(histories: &Vec<Vec<i64>>) -> i64 {
+        part1(histories)
+    }
+
+    fn part2(histories: &Vec<Vec<i64>>) -> i64 {
+        part2(histories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day09-1.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("0 3 6 9\n1 3 x 10").unwrap_err();
+        assert_eq!("2:5: expected a number, found 'x'", err.to_string());
+
+        let err = parse("0 3 6 9\n\n1 3 6 10").unwrap_err();
+        assert_eq!("2:1: expected a history of numbers", err.to_string());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos, NEIGHBORS4};
+use crate::solver::Solver;
+
+use std::collections::VecDeque;
+
+// pipe grid and the start point
+pub type Maze = (Grid<Pipe>, Pos);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pipe {
+    Vertical,   // |
+    Horizontal, // -
+    NorthEast,  // L
+    NorthWest,  // J
+    SouthWest,  // 7
+    SouthEast,  // F
+    Start,      // S
+    Ground,     // .
+}
+
+const SHAPES: [Pipe; 6] = [
+    Pipe::Vertical,
+    Pipe::Horizontal,
+    Pipe::NorthEast,
+    Pipe::NorthWest,
+    Pipe::SouthWest,
+    Pipe::SouthEast,
+];
+
+impl Pipe {
+    fn from_char(c: char) -> Option<Pipe> {
+        match c {
+            '|' => Some(Pipe::Vertical),
+            '-' => Some(Pipe::Horizontal),
+            'L' => Some(Pipe::NorthEast),
+            'J' => Some(Pipe::NorthWest),
+            '7' => Some(Pipe::SouthWest),
+            'F' => Some(Pipe::SouthEast),
+            'S' => Some(Pipe::Start),
+            '.' => Some(Pipe::Ground),
+            _ => None,
+        }
+    }
+
+    /// The (row, column) offsets this pipe connects to
+    pub fn connections(self) -> &'static [(isize, isize)] {
+        match self {
+            Pipe::Vertical => &[(-1, 0), (1, 0)],
+            Pipe::Horizontal => &[(0, -1), (0, 1)],
+            Pipe::NorthEast => &[(-1, 0), (0, 1)],
+            Pipe::NorthWest => &[(-1, 0), (0, -1)],
+            Pipe::SouthWest => &[(1, 0), (0, -1)],
+            Pipe::SouthEast => &[(1, 0), (0, 1)],
+            Pipe::Start | Pipe::Ground => &[],
+        }
+    }
+}
+
+pub fn determine_start_pipe_shape(grid: &Grid<Pipe>, start_point: Pos) -> Option<Pipe> {
+    // the neighbors whose pipes lead back to the start
+    let connections: Vec<(isize, isize)> = NEIGHBORS4
+        .into_iter()
+        .filter(|&(dr, dc)| {
+            grid.step(start_point, (dr, dc))
+                .is_some_and(|neighbor| grid[neighbor].connections().contains(&(-dr, -dc)))
+        })
+        .collect();
+
+    SHAPES.into_iter().find(|shape| {
+        connections.len() == 2 && shape.connections().iter().all(|c| connections.contains(c))
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let mut grid = Grid::parse(input, Pipe::from_char)?;
+    let lines: Vec<&str> = input.lines().collect();
+    // tiles are all ascii once parsed, so columns are byte offsets
+    let tile = |(row, col): Pos| (row, lines[row], &lines[row][col..col + 1]);
+
+    let starts: Vec<Pos> = grid
+        .iter()
+        .filter(|&(_, &pipe)| pipe == Pipe::Start)
+        .map(|(pos, _)| pos)
+        .collect();
+    let Some(&start_point) = starts.first() else {
+        let last = lines.len() - 1;
+        return Err(ParseError::at_end(
+            last,
+            lines[last],
+            "no start tile 'S' in maze",
+        ));
+    };
+    if let Some(&second) = starts.get(1) {
+        let (row, line, tile) = tile(second);
+        return Err(ParseError::at(row, line, tile, "second start tile"));
+    }
+
+    let start_pipe = determine_start_pipe_shape(&grid, start_point).ok_or_else(|| {
+        let (row, line, tile) = tile(start_point);
+        ParseError::at(
+            row,
+            line,
+            tile,
+            "start tile does not connect to exactly two pipes",
+        )
+    })?;
+    grid[start_point] = start_pipe;
+
+    Ok((grid, start_point))
+}
+
+pub fn get_neighbors(grid: &Grid<Pipe>, point: Pos) -> impl Iterator<Item = Pos> + '_ {
+    grid[point]
+        .connections()
+        .iter()
+        .filter_map(move |&offset| grid.step(point, offset))
+}
+
+/// The distance to the farthest point of the loop, and which tiles are on the loop
+pub fn find_farthest_point(grid: &Grid<Pipe>, start_point: Pos) -> (usize, Grid<bool>) {
+    let mut distances = Grid::new(grid.width(), grid.height(), None);
+    let mut queue = VecDeque::new();
+
+    distances[start_point] = Some(0);
+    queue.push_back(start_point);
+
+    while let Some(point) = queue.pop_front() {
+        let distance = distances[point].unwrap_or(0);
+        for neighbor in get_neighbors(grid, point) {
+            if distances[neighbor].is_none() {
+                distances[neighbor] = Some(distance + 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    let farthest = distances.iter().filter_map(|(_, d)| *d).max().unwrap_or(0);
+    (farthest, distances.map(Option::is_some))
+}
+
+// point in polygon, counting the loop pipes above each tile that head left and right
+pub fn in_loop(grid: &Grid<Pipe>, loop_pipes: &Grid<bool>) -> Vec<Pos> {
+    let rights = [Pipe::Horizontal, Pipe::NorthEast, Pipe::SouthEast];
+    let lefts = [Pipe::Horizontal, Pipe::NorthWest, Pipe::SouthWest];
+
+    grid.positions()
+        .filter(|&pos| !loop_pipes[pos])
+        .filter(|&(row, col)| {
+            let above: Vec<Pipe> = grid
+                .column(col)
+                .zip(loop_pipes.column(col))
+                .take(row)
+                .filter(|&(_, &on_loop)| on_loop)
+                .map(|(&pipe, _)| pipe)
+                .collect();
+            let left = above.iter().filter(|pipe| lefts.contains(pipe)).count();
+            let right = above.iter().filter(|pipe| rights.contains(pipe)).count();
+
+            left.min(right) % 2 == 1
+        })
+        .collect()
+}
+
+pub fn part1((grid, start_point): &Maze) -> usize {
+    find_farthest_point(grid, *start_point).0
+}
+
+pub fn part2((grid, start_point): &Maze) -> usize {
+    let (_, loop_pipes) = find_farthest_point(grid, *start_point);
+
+    in_loop(grid, &loop_pipes).len()
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day10");
+
+    type Parsed<'a> = Maze;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(maze: &Maze) -> usize {
+        part1(maze)
+    }
+
+    fn part2(maze: &Maze) -> usize {
+        part2(maze)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2};
+    use crate::example::Example;
+
+    const EXAMPLE1: &str = include_str!("../../inputs/examples/2023/day10-1.txt");
+    const EXAMPLE2: &str = include_str!("../../inputs/examples/2023/day10-2.txt");
+    const EXAMPLE3: &str = include_str!("../../inputs/examples/2023/day10-3.txt");
+
+    #[test]
+    fn part1_test() {
+        let example = Example::parse(EXAMPLE1).unwrap();
+        assert_eq!(
+            example.part1,
+            Some(part1(&parse_input(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let example = Example::parse(EXAMPLE2).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse_input(&example.input).unwrap()).to_string())
+        );
+
+        let example = Example::parse(EXAMPLE3).unwrap();
+        assert_eq!(
+            example.part2,
+            Some(part2(&parse_input(&example.input).unwrap()).to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse_input("..F7.\n.FJ|.\nS-JL7").unwrap_err();
+        assert_eq!(
+            "3:1: start tile does not connect to exactly two pipes 'S'",
+            err.to_string()
+        );
+
+        let err = parse_input("..F7.\n.FJx.").unwrap_err();
+        assert_eq!((2, 4), (err.line, err.column));
+    }
+}
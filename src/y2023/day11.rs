@@ -0,0 +1,114 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::solver::Solver;
+
+pub fn manhattan_distance(
+    a: Pos,
+    b: Pos,
+    empty_rows: &[bool],
+    empty_cols: &[bool],
+    expansion_factor: i64,
+) -> i64 {
+    let mut distance = 0;
+
+    // calc row distance
+    let row_distance = (a.0 as i64 - b.0 as i64).abs();
+    let row_expansion = if a.0 != b.0 {
+        empty_rows[a.0.min(b.0)..=a.0.max(b.0)]
+            .iter()
+            .filter(|&&empty| empty)
+            .count() as i64
+    } else {
+        0
+    };
+    distance += row_distance + row_expansion * (expansion_factor - 1);
+
+    // calc column distance
+    let col_distance = (a.1 as i64 - b.1 as i64).abs();
+    let col_expansion = if a.1 != b.1 {
+        empty_cols[a.1.min(b.1)..=a.1.max(b.1)]
+            .iter()
+            .filter(|&&empty| empty)
+            .count() as i64
+    } else {
+        0
+    };
+    distance += col_distance + col_expansion * (expansion_factor - 1);
+
+    distance
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c))
+}
+
+pub fn solve(grid: &Grid<char>, expansion_factor: i64) -> i64 {
+    let empty_rows: Vec<bool> = grid.rows().map(|row| !row.contains(&'#')).collect();
+    let empty_cols: Vec<bool> = grid
+        .columns()
+        .map(|mut col| col.all(|&cell| cell != '#'))
+        .collect();
+
+    let galaxies: Vec<Pos> = grid
+        .iter()
+        .filter(|&(_, &cell)| cell == '#')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut total_distance = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            total_distance += manhattan_distance(
+                galaxies[i],
+                galaxies[j],
+                &empty_rows,
+                &empty_cols,
+                expansion_factor,
+            );
+        }
+    }
+
+    total_distance
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const INPUT: &'static str = include_str!("../../inputs/2023/day11");
+
+    type Parsed<'a> = Grid<char>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> i64 {
+        solve(grid, 2)
+    }
+
+    fn part2(grid: &Grid<char>) -> i64 {
+        solve(grid, 1000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, solve};
+    use crate::example::Example;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/2023/day11-1.txt");
+
+    #[test]
+    fn solve_test() {
+        let example = Example::parse(EXAMPLE).unwrap();
+        let grid = parse(&example.input).unwrap();
+
+        assert_eq!(example.part1, Some(solve(&grid, 2).to_string()));
+        assert_eq!(1030, solve(&grid, 10));
+        assert_eq!(8410, solve(&grid, 100));
+    }
+}
@@ -0,0 +1,87 @@
+use adventofcode23::example::Example;
+use adventofcode23::input::normalize;
+use adventofcode23::solver::Part;
+use adventofcode23::solvers;
+
+// (year, day, part, example input, answers) for every example file
+fn examples() -> Vec<(usize, usize, Part, String, String)> {
+    adventofcode23::examples()
+        .into_iter()
+        .map(|file| {
+            let example = Example::parse(file.text).unwrap();
+            let part = example.parts(Part::Both).unwrap();
+            let answers = [example.part1, example.part2]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            (file.year, file.day, part, example.input, answers)
+        })
+        .collect()
+}
+
+fn solve(year: usize, day: usize, part: Part, input: &str) -> String {
+    let solver = solvers()
+        .into_iter()
+        .find(|s| (s.year(), s.day()) == (year, day))
+        .unwrap();
+    let solution = solver.solve(&normalize(input), part).unwrap();
+
+    [solution.part1, solution.part2]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn examples_test() {
+    for (year, day, part, example, answer) in examples() {
+        assert_eq!(answer, solve(year, day, part, &example), "day {}", day);
+    }
+}
+
+#[test]
+fn crlf_test() {
+    for (year, day, part, example, answer) in examples() {
+        let crlf = example.replace('\n', "\r\n");
+
+        assert_eq!(answer, solve(year, day, part, &crlf), "day {}", day);
+        assert_eq!(
+            answer,
+            solve(year, day, part, &(crlf + "\r\n")),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn trailing_newline_test() {
+    for (year, day, part, example, answer) in examples() {
+        assert_eq!(
+            answer,
+            solve(year, day, part, &format!("{}\n", example)),
+            "day {}",
+            day
+        );
+        assert_eq!(
+            answer,
+            solve(year, day, part, &format!("{}\n\n", example)),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn bom_test() {
+    for (year, day, part, example, answer) in examples() {
+        assert_eq!(
+            answer,
+            solve(year, day, part, &format!("\u{feff}{}\n", example)),
+            "day {}",
+            day
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+use adventofcode23::history::{History, Verdict};
+use adventofcode23::remote::{fetch_input, submit_answer, submit_checked, Config, Fetched};
+
+// serve a single canned response, handing back the request that was received
+fn serve_once(status: &str, body: &str) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = String::new();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                break;
+            }
+            request.push_str(&line);
+        }
+
+        let length = request
+            .lines()
+            .find_map(|line| {
+                line.to_lowercase()
+                    .strip_prefix("content-length: ")?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        request.push_str("\r\n");
+        request.push_str(&String::from_utf8(body).unwrap());
+        stream.write_all(response.as_bytes()).unwrap();
+        tx.send(request).unwrap();
+    });
+
+    (base_url, rx)
+}
+
+fn config(base_url: String) -> Config {
+    Config {
+        session: Some("cafe".to_string()),
+        base_url: Some(base_url),
+    }
+}
+
+#[test]
+fn fetch_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let (base_url, requests) = serve_once("200 OK", "0 3 6 9 12 15\n");
+    let config = config(base_url);
+
+    let path = dir.join("2023/day09");
+    assert_eq!(
+        Ok(Fetched::Downloaded(path.clone())),
+        fetch_input(&config, 2023, 9, &dir)
+    );
+    assert_eq!("0 3 6 9 12 15\n", fs::read_to_string(&path).unwrap());
+
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+    assert!(request.to_lowercase().contains("cookie: session=cafe\r\n"));
+
+    // the server is gone by now, so this has to come from the cache
+    assert_eq!(
+        Ok(Fetched::Cached(path)),
+        fetch_input(&config, 2023, 9, &dir)
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch_error_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-error-{}", std::process::id()));
+    let (base_url, _requests) = serve_once("400 Bad Request", "");
+
+    let err = fetch_input(&config(base_url), 2023, 9, &dir).unwrap_err();
+
+    assert!(err.ends_with("rejected the session cookie"), "{}", err);
+    assert!(!dir.join("2023/day09").exists());
+}
+
+#[test]
+fn submit_test() {
+    let page = "<html><main><article><p>That's not the right answer; \
+                your answer is too low.</p></article></main></html>";
+    let (base_url, requests) = serve_once("200 OK", page);
+
+    let (verdict, message) = submit_answer(&config(base_url), 2023, 5, 2, "1068").unwrap();
+    assert_eq!(Verdict::TooLow, verdict);
+    assert!(message.starts_with("That's not the right answer"));
+
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+    assert!(request.to_lowercase().contains("cookie: session=cafe\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=2&answer=1068"));
+}
+
+#[test]
+fn submit_checked_test() {
+    let page = "<html><main><article><p>That's not the right answer; \
+                your answer is too high.</p></article></main></html>";
+    let (base_url, requests) = serve_once("200 OK", page);
+    let config = config(base_url);
+    let path = std::env::temp_dir().join(format!("aoc-history-{}.toml", std::process::id()));
+
+    let submitted = submit_checked(&config, &path, 2023, 5, 2, "1068");
+    // nothing is sent for an answer the first attempt already rules out
+    let refused = submit_checked(&config, &path, 2023, 5, 2, "2000");
+    let history = History::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(Verdict::TooHigh, submitted.unwrap().0);
+    assert_eq!(
+        Err("not submitting, 2000 can't be right, 1068 was too high".to_string()),
+        refused
+    );
+    assert!(requests.recv().is_ok());
+    let attempts = history.unwrap().attempts;
+    assert_eq!(1, attempts.len());
+    assert_eq!(
+        ("1068", Verdict::TooHigh),
+        (attempts[0].answer.as_str(), attempts[0].verdict)
+    );
+}
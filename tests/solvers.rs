@@ -0,0 +1,39 @@
+use adventofcode23::range::RangeSet;
+use adventofcode23::solver::Part;
+use adventofcode23::solvers;
+use adventofcode23::y2023::{day05, day07, day11};
+
+#[test]
+fn registry_test() {
+    let days: Vec<(usize, usize)> = solvers().iter().map(|s| (s.year(), s.day())).collect();
+    let days_2023: Vec<usize> = days.iter().filter(|d| d.0 == 2023).map(|d| d.1).collect();
+
+    assert_eq!((1..=11).collect::<Vec<_>>(), days_2023[..11]);
+    assert!(days.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn dyn_solve_test() {
+    let input = "32T3K 765\nT55J5 684";
+    let solver = solvers()
+        .into_iter()
+        .find(|s| (s.year(), s.day()) == (2023, 7))
+        .unwrap();
+    let solution = solver.solve(input, Part::Both).unwrap();
+    let bids = day07::parse(input).unwrap();
+
+    assert_eq!(Some(day07::part1(&bids).to_string()), solution.part1);
+    assert_eq!(Some(day07::part2(&bids).to_string()), solution.part2);
+    assert_eq!(Some("2133".to_string()), solution.part1);
+}
+
+#[test]
+fn helpers_test() {
+    let grid = day11::parse("#.\n.#").unwrap();
+    assert_eq!(2, day11::solve(&grid, 1));
+
+    // a single shifted entry splits the seed range in two
+    let almanac = day05::parse("seeds: 0 10\n\nseed-to-soil map:\n100 5 10").unwrap();
+    let ranges = almanac.maps[0].apply_set(&RangeSet::from_range(0, 9));
+    assert_eq!(&[(0, 4), (100, 104)], ranges.ranges());
+}